@@ -0,0 +1,192 @@
+//! Timing benchmark for `PeekN::next_if` rollbacks against a prototype growable ring.
+//!
+//! `GrowRing` mirrors the design of the crate's fixed-size `Buffer`, but is heap-backed
+//! and doubles its capacity when full. It is only used here to compare against the
+//! `VecDeque` that backs `PeekN`.
+//!
+//! `VecDeque::push_front` is already O(1) and never rotates the buffer, and in these loops
+//! it stays well ahead of the prototype, so `PeekN` keeps its `VecDeque` buffer.
+//!
+//! Run with `cargo bench --bench next_if`.
+
+use std::collections::VecDeque;
+use std::hint::black_box;
+use std::time::Instant;
+
+use peeknth::peekn;
+
+const ITERATIONS: usize = 1_000_000;
+const LOOKAHEAD: usize = 8;
+
+struct GrowRing<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> GrowRing<T> {
+    fn new() -> Self {
+        GrowRing {
+            slots: Vec::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn grow(&mut self) {
+        let cap = (self.slots.len() * 2).max(4);
+        let mut slots: Vec<Option<T>> = (0..cap).map(|_| None).collect();
+        for (i, slot) in slots.iter_mut().take(self.len).enumerate() {
+            let idx = (self.head + i) % self.slots.len();
+            *slot = self.slots[idx].take();
+        }
+        self.slots = slots;
+        self.head = 0;
+    }
+
+    fn push_back(&mut self, item: T) {
+        if self.len == self.slots.len() {
+            self.grow();
+        }
+        let idx = (self.head + self.len) % self.slots.len();
+        self.slots[idx] = Some(item);
+        self.len += 1;
+    }
+
+    fn push_front(&mut self, item: T) {
+        if self.len == self.slots.len() {
+            self.grow();
+        }
+        self.head = (self.head + self.slots.len() - 1) % self.slots.len();
+        self.slots[self.head] = Some(item);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.slots.len();
+        self.len -= 1;
+        item
+    }
+
+    fn get(&self, n: usize) -> Option<&T> {
+        if n >= self.len {
+            return None;
+        }
+        self.slots[(self.head + n) % self.slots.len()].as_ref()
+    }
+}
+
+/// Minimal `PeekN` stand-in over a `GrowRing`, with the same `next_if` logic.
+struct RingPeek<I: Iterator> {
+    iter: I,
+    buffer: GrowRing<I::Item>,
+}
+
+impl<I: Iterator> RingPeek<I> {
+    fn next(&mut self) -> Option<I::Item> {
+        self.buffer.pop_front().or_else(|| self.iter.next())
+    }
+
+    fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        while self.buffer.len <= n {
+            let item = self.iter.next()?;
+            self.buffer.push_back(item);
+        }
+        self.buffer.get(n)
+    }
+
+    fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next() {
+            Some(matched) if func(&matched) => Some(matched),
+            Some(other) => {
+                self.buffer.push_front(other);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+fn bench(name: &str, mut f: impl FnMut() -> usize) {
+    let start = Instant::now();
+    let sum = black_box(f());
+    let elapsed = start.elapsed();
+    println!("{name:<28} {elapsed:>12?} (checksum {sum})");
+}
+
+fn main() {
+    bench("VecDeque next_if rollback", || {
+        let mut iter = peekn(0..ITERATIONS);
+        let mut sum = 0;
+        loop {
+            if let Some(x) = iter.next_if(|&x| x % 4 == 0) {
+                sum += black_box(x);
+            } else if let Some(x) = iter.next() {
+                sum += black_box(x) & 1;
+            } else {
+                break;
+            }
+        }
+        sum
+    });
+
+    bench("GrowRing next_if rollback", || {
+        let mut iter = RingPeek {
+            iter: 0..ITERATIONS,
+            buffer: GrowRing::new(),
+        };
+        let mut sum = 0;
+        loop {
+            if let Some(x) = iter.next_if(|&x| x % 4 == 0) {
+                sum += black_box(x);
+            } else if let Some(x) = iter.next() {
+                sum += black_box(x) & 1;
+            } else {
+                break;
+            }
+        }
+        sum
+    });
+
+    bench("VecDeque lookahead+rollback", || {
+        let mut iter = peekn(0..ITERATIONS);
+        let mut sum = 0;
+        while let Some(&x) = iter.peek_nth(LOOKAHEAD) {
+            sum += black_box(x) & 1;
+            if iter.next_if(|&x| x % 4 == 0).is_none() {
+                iter.next();
+            }
+        }
+        sum
+    });
+
+    bench("GrowRing lookahead+rollback", || {
+        let mut iter = RingPeek {
+            iter: 0..ITERATIONS,
+            buffer: GrowRing::new(),
+        };
+        let mut sum = 0;
+        while let Some(&x) = iter.peek_nth(LOOKAHEAD) {
+            sum += black_box(x) & 1;
+            if iter.next_if(|&x| x % 4 == 0).is_none() {
+                iter.next();
+            }
+        }
+        sum
+    });
+
+    // Keep the baseline container in view: a bare `VecDeque` push_front/pop_front pair.
+    bench("VecDeque push_front/pop", || {
+        let mut ring = VecDeque::new();
+        let mut sum = 0;
+        for x in 0..ITERATIONS {
+            ring.push_front(x);
+            sum += black_box(ring.pop_front().unwrap_or(0)) & 1;
+        }
+        sum
+    });
+}
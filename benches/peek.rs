@@ -0,0 +1,50 @@
+//! Simple timing benchmark for `PeekN::peek` against `peek_nth(0)`.
+//!
+//! Run with `cargo bench --bench peek`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use peeknth::peekn;
+
+const ITERATIONS: usize = 1_000_000;
+
+fn bench(name: &str, mut f: impl FnMut() -> usize) {
+    let start = Instant::now();
+    let sum = black_box(f());
+    let elapsed = start.elapsed();
+    println!("{name:<16} {elapsed:>12?} (checksum {sum})");
+}
+
+fn main() {
+    bench("peek", || {
+        let mut iter = peekn(0..ITERATIONS);
+        let mut sum = 0;
+        while let Some(&x) = iter.peek() {
+            sum += black_box(x);
+            iter.next();
+        }
+        sum
+    });
+
+    bench("peek_nth(0)", || {
+        let mut iter = peekn(0..ITERATIONS);
+        let mut sum = 0;
+        while let Some(&x) = iter.peek_nth(0) {
+            sum += black_box(x);
+            iter.next();
+        }
+        sum
+    });
+
+    bench("peek_mut", || {
+        let mut iter = peekn(0..ITERATIONS);
+        let mut sum = 0;
+        while let Some(x) = iter.peek_mut() {
+            *x += 1;
+            sum += black_box(*x);
+            iter.next();
+        }
+        sum
+    });
+}
@@ -0,0 +1,353 @@
+//! Storage backends for the fixed-size peek adapters.
+//!
+//! [`SizedPeekN`](crate::SizedPeekN) and [`SizedPeekDN`](crate::SizedPeekDN) store their
+//! lookahead in any [`PeekBuffer`]. By default this is [`Buffer`], an inline ring buffer
+//! that never allocates and panics when full. With the `smallvec` feature,
+//! `smallvec::SmallVec` can be used instead: it keeps up to its inline size on the stack
+//! and spills to the heap past that.
+
+use core::ops::Range;
+
+pub use crate::util::Buffer;
+
+/// A double-ended queue used as peek storage.
+///
+/// Items are indexed from the front: index `0` is the item `pop_front` returns next.
+///
+/// Only the basic queue operations are required; the rest have default implementations
+/// built on [`as_slices`](Self::as_slices) and [`as_mut_slices`](Self::as_mut_slices).
+pub trait PeekBuffer<T> {
+    /// Appends an item at the back.
+    ///
+    /// # Panics
+    /// May panic if the buffer already holds [`capacity`](Self::capacity) items.
+    fn push_back(&mut self, value: T);
+
+    /// Prepends an item at the front.
+    ///
+    /// # Panics
+    /// May panic if the buffer already holds [`capacity`](Self::capacity) items.
+    fn push_front(&mut self, value: T);
+
+    /// Removes and returns the first item.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes and returns the last item.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns the number of items held.
+    fn len(&self) -> usize;
+
+    /// Returns the maximum number of items the buffer can hold.
+    ///
+    /// Buffers that grow on demand return `usize::MAX`.
+    fn capacity(&self) -> usize;
+
+    /// Removes all items.
+    fn clear(&mut self);
+
+    /// Returns the items as two slices that, concatenated, hold them in order.
+    fn as_slices(&self) -> (&[T], &[T]);
+
+    /// Mutable version of [`as_slices`](Self::as_slices).
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]);
+
+    /// Returns `true` if the buffer holds no items.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the item at `index`, or `None` if it is out of bounds.
+    fn get(&self, index: usize) -> Option<&T> {
+        let (head, tail) = self.as_slices();
+        match head.get(index) {
+            Some(item) => Some(item),
+            None => tail.get(index - head.len()),
+        }
+    }
+
+    /// Returns the item at `index` mutably, or `None` if it is out of bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (head, tail) = self.as_mut_slices();
+        let split = head.len();
+        match head.get_mut(index) {
+            Some(item) => Some(item),
+            None => tail.get_mut(index - split),
+        }
+    }
+
+    /// Returns an iterator over the items in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range.start > range.end` or `range.end > len()`.
+    fn range<'a>(&'a self, range: Range<usize>) -> impl DoubleEndedIterator<Item = &'a T> + Clone
+    where
+        T: 'a,
+    {
+        assert!(range.end <= self.len(), "range out of bounds");
+        let (head, tail) = self.as_slices();
+        let split = head.len();
+        head[range.start.min(split)..range.end.min(split)]
+            .iter()
+            .chain(tail[range.start.saturating_sub(split)..range.end.saturating_sub(split)].iter())
+    }
+
+    /// Mutable version of [`range`](Self::range).
+    fn range_mut<'a>(
+        &'a mut self,
+        range: Range<usize>,
+    ) -> impl DoubleEndedIterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        assert!(range.end <= self.len(), "range out of bounds");
+        let (head, tail) = self.as_mut_slices();
+        let split = head.len();
+        head[range.start.min(split)..range.end.min(split)]
+            .iter_mut()
+            .chain(
+                tail[range.start.saturating_sub(split)..range.end.saturating_sub(split)].iter_mut(),
+            )
+    }
+
+    /// Returns the items in `range` as one slice, or `None` if they are not contiguous.
+    fn slice_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        let (head, tail) = self.as_mut_slices();
+        let split = head.len();
+        if range.end <= split {
+            head.get_mut(range)
+        } else if range.start >= split {
+            tail.get_mut(range.start - split..range.end - split)
+        } else {
+            None
+        }
+    }
+
+    /// Removes the first `count` items.
+    fn drain_front(&mut self, count: usize) {
+        for _ in 0..count {
+            self.pop_front();
+        }
+    }
+
+    /// Reverses the order of the items in place.
+    fn reverse(&mut self) {
+        let len = self.len();
+        let (head, tail) = self.as_mut_slices();
+        let split = head.len();
+        for i in 0..len / 2 {
+            let j = len - 1 - i;
+            if j < split {
+                head.swap(i, j);
+            } else if i >= split {
+                tail.swap(i - split, j - split);
+            } else {
+                core::mem::swap(&mut head[i], &mut tail[j - split]);
+            }
+        }
+    }
+
+    /// Rotates the items so that the one at `mid` becomes the first.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`.
+    fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotate_left: mid out of bounds");
+        for _ in 0..mid {
+            if let Some(item) = self.pop_front() {
+                self.push_back(item);
+            }
+        }
+    }
+
+    /// Rotates the items so that the last `k` become the first.
+    ///
+    /// # Panics
+    /// Panics if `k > len()`.
+    fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "rotate_right: k out of bounds");
+        for _ in 0..k {
+            if let Some(item) = self.pop_back() {
+                self.push_front(item);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> PeekBuffer<T> for Buffer<T, N> {
+    #[inline]
+    fn push_back(&mut self, value: T) {
+        Buffer::push_back(self, value)
+    }
+
+    #[inline]
+    fn push_front(&mut self, value: T) {
+        Buffer::push_front(self, value)
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<T> {
+        Buffer::pop_front(self)
+    }
+
+    #[inline]
+    fn pop_back(&mut self) -> Option<T> {
+        Buffer::pop_back(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        Buffer::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        Buffer::clear(self)
+    }
+
+    #[inline]
+    fn as_slices(&self) -> (&[T], &[T]) {
+        Buffer::as_slices(self)
+    }
+
+    #[inline]
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        Buffer::as_mut_slices(self)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&T> {
+        Buffer::get(self, index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        Buffer::get_mut(self, index)
+    }
+
+    #[inline]
+    fn range<'a>(&'a self, range: Range<usize>) -> impl DoubleEndedIterator<Item = &'a T> + Clone
+    where
+        T: 'a,
+    {
+        Buffer::range(self, range)
+    }
+
+    #[inline]
+    fn range_mut<'a>(
+        &'a mut self,
+        range: Range<usize>,
+    ) -> impl DoubleEndedIterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        Buffer::range_mut(self, range)
+    }
+
+    #[inline]
+    fn slice_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        Buffer::slice_mut(self, range)
+    }
+
+    #[inline]
+    fn drain_front(&mut self, count: usize) {
+        Buffer::drain(self, ..count)
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        Buffer::reverse(self)
+    }
+
+    #[inline]
+    fn rotate_left(&mut self, mid: usize) {
+        Buffer::rotate_left(self, mid)
+    }
+
+    #[inline]
+    fn rotate_right(&mut self, k: usize) {
+        Buffer::rotate_right(self, k)
+    }
+}
+
+/// A spilling buffer: it holds up to `A::size()` items inline and moves to the heap past
+/// that, so it is never full.
+///
+/// `push_front` and `pop_front` shift the stored items, so this suits short lookahead.
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> PeekBuffer<A::Item> for smallvec::SmallVec<A> {
+    #[inline]
+    fn push_back(&mut self, value: A::Item) {
+        self.push(value)
+    }
+
+    #[inline]
+    fn push_front(&mut self, value: A::Item) {
+        self.insert(0, value)
+    }
+
+    #[inline]
+    fn pop_front(&mut self) -> Option<A::Item> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove(0))
+        }
+    }
+
+    #[inline]
+    fn pop_back(&mut self) -> Option<A::Item> {
+        self.pop()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        smallvec::SmallVec::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        smallvec::SmallVec::clear(self)
+    }
+
+    #[inline]
+    fn as_slices(&self) -> (&[A::Item], &[A::Item]) {
+        (self, &[])
+    }
+
+    #[inline]
+    fn as_mut_slices(&mut self) -> (&mut [A::Item], &mut [A::Item]) {
+        (self, &mut [])
+    }
+
+    #[inline]
+    fn drain_front(&mut self, count: usize) {
+        self.drain(..count);
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    #[inline]
+    fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid)
+    }
+
+    #[inline]
+    fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k)
+    }
+}
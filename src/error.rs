@@ -0,0 +1,99 @@
+//! Error types returned by the fallible peek methods.
+
+/// The error type shared by the fallible peek methods.
+///
+/// For example, [`SizedPeekN::try_peek_nth`](crate::SizedPeekN::try_peek_nth) and
+/// [`SizedPeekN::try_peek_nth_mut`](crate::SizedPeekN::try_peek_nth_mut) return
+/// [`PeekError::OverCapacity`] when `requested >= capacity`, and the `TryFrom` conversions
+/// into the fixed-size peekers report [`PeekError::BufferOverflow`] through an
+/// [`IntoSizedError`].
+///
+/// New variants may be added in future versions, so matches must include a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PeekError {
+    /// A peek index does not fit in a fixed-capacity buffer.
+    OverCapacity {
+        /// The index that was requested.
+        requested: usize,
+        /// The capacity of the peek buffer.
+        capacity: usize,
+    },
+    /// More items were supplied than a fixed-capacity buffer can hold.
+    BufferOverflow {
+        /// The capacity of the buffer.
+        capacity: usize,
+    },
+    /// The source holds an item reserved by [`PeekN::peek_last`](crate::PeekN::peek_last),
+    /// which the target has no place for.
+    ReservedLast,
+}
+
+impl core::fmt::Display for PeekError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PeekError::OverCapacity {
+                requested,
+                capacity,
+            } => write!(
+                f,
+                "peek index {requested} is out of bounds for a buffer of capacity {capacity}"
+            ),
+            PeekError::BufferOverflow { capacity } => {
+                write!(f, "buffer overflow: capacity is {capacity}")
+            }
+            PeekError::ReservedLast => {
+                f.write_str("an item reserved by peek_last cannot be carried over")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PeekError {}
+
+/// A failed conversion into a peeker with less buffer room than its source.
+///
+/// Returned by the `TryFrom` impls of [`SizedPeekN`](crate::SizedPeekN) and
+/// [`SizedPeekDN`](crate::SizedPeekDN), and by the conversion from
+/// [`PeekableDE`](crate::PeekableDE) into [`PeekN`](crate::PeekN). The source value is
+/// handed back unchanged, so no buffered items are lost.
+pub struct IntoSizedError<T> {
+    pub(crate) source: T,
+    pub(crate) error: PeekError,
+}
+
+impl<T> IntoSizedError<T> {
+    /// Returns the reason the conversion failed.
+    #[inline]
+    pub fn error(&self) -> PeekError {
+        self.error
+    }
+
+    /// Returns a reference to the value the conversion was attempted on.
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.source
+    }
+
+    /// Returns the value the conversion was attempted on.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.source
+    }
+}
+
+impl<T> core::fmt::Debug for IntoSizedError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IntoSizedError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> core::fmt::Display for IntoSizedError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<T> core::error::Error for IntoSizedError<T> {}
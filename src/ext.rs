@@ -0,0 +1,77 @@
+//! Extension traits for building peek adapters by method chaining.
+
+#[cfg(all(feature = "peekn", feature = "alloc"))]
+use crate::PeekN;
+#[cfg(feature = "peekn")]
+use crate::SizedPeekN;
+
+#[cfg(all(feature = "peekdn", feature = "alloc"))]
+use crate::PeekDN;
+#[cfg(feature = "peekdn")]
+use crate::SizedPeekDN;
+
+#[cfg(feature = "peekde")]
+use crate::PeekableDE;
+
+/// Adds `.peekn()` and `.sizedpeekn::<S>()` to every iterator.
+///
+/// # Example
+/// ```
+/// use peeknth::IteratorPeekExt;
+///
+/// let mut iter = (0..10).filter(|x| x % 2 == 0).peekn();
+/// assert_eq!(iter.peek_nth(1), Some(&2));
+/// ```
+#[cfg(feature = "peekn")]
+pub trait IteratorPeekExt: Iterator + Sized {
+    /// Wraps the iterator in a [`PeekN`]. Equivalent to [`peekn`](crate::peekn).
+    #[cfg(feature = "alloc")]
+    fn peekn(self) -> PeekN<Self> {
+        PeekN::new(self)
+    }
+
+    /// Wraps the iterator in a [`SizedPeekN`]. Equivalent to [`sizedpeekn`](crate::sizedpeekn).
+    fn sizedpeekn<const S: usize>(self) -> SizedPeekN<Self, S> {
+        SizedPeekN::new(self)
+    }
+}
+
+#[cfg(feature = "peekn")]
+impl<I: Iterator> IteratorPeekExt for I {}
+
+/// Adds `.peekdn()`, `.sizedpeekdn::<F, B>()` and `.peekablede()` to every
+/// double-ended iterator (depending on enabled features).
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "peekdn", feature = "alloc"))]
+/// # {
+/// use peeknth::DoubleEndedPeekExt;
+///
+/// let mut iter = (1..=5).map(|x| x * 10).peekdn();
+/// assert_eq!(iter.peek_back(), Some(&50));
+/// # }
+/// ```
+#[cfg(any(feature = "peekdn", feature = "peekde"))]
+pub trait DoubleEndedPeekExt: DoubleEndedIterator + Sized {
+    /// Wraps the iterator in a [`PeekDN`]. Equivalent to [`peekdn`](crate::peekdn).
+    #[cfg(all(feature = "peekdn", feature = "alloc"))]
+    fn peekdn(self) -> PeekDN<Self> {
+        PeekDN::new(self)
+    }
+
+    /// Wraps the iterator in a [`SizedPeekDN`]. Equivalent to [`sizedpeekdn`](crate::sizedpeekdn).
+    #[cfg(feature = "peekdn")]
+    fn sizedpeekdn<const F: usize, const B: usize>(self) -> SizedPeekDN<Self, F, B> {
+        SizedPeekDN::new(self)
+    }
+
+    /// Wraps the iterator in a [`PeekableDE`]. Equivalent to [`peekablede`](crate::peekablede).
+    #[cfg(feature = "peekde")]
+    fn peekablede(self) -> PeekableDE<Self> {
+        PeekableDE::new(self)
+    }
+}
+
+#[cfg(any(feature = "peekdn", feature = "peekde"))]
+impl<I: DoubleEndedIterator> DoubleEndedPeekExt for I {}
@@ -9,7 +9,7 @@
 //!
 //! - **`peekn`** — Forward peeking multiple steps (`PeekN`, `SizedPeekN`)
 //! - **`peekdn`** — Double-ended peeking from both front/back (`PeekDn`, `SizedPeekDn`)
-//! - **`peekde`** — Lightweight double-ended peek (1 element each, via `PeekableDE`)
+//! - **`peekde`** — Lightweight double-ended peek (up to 2 elements each, via `PeekableDE`)
 //!
 //! ## `no_std` Support
 //!
@@ -25,18 +25,44 @@ extern crate alloc;
 #[cfg(feature = "peekn")]
 mod peekn;
 #[cfg(feature = "peekn")]
-pub use peekn::{PeekN, SizedPeekN, peekn, sizedpeekn};
+pub use peekn::{
+    BoxedPeekN, Checksummed, Lookahead, PeekEntry, PeekMut, PeekN, PeekRange, PeekRangeMut,
+    ScanPeeked, SizedPeekN, VacantEntry, peekn, peekn_boxed, sizedpeekn,
+};
 
 #[cfg(feature = "peekdn")]
 mod peekdn;
 #[cfg(feature = "peekdn")]
-pub use peekdn::{PeekDN, SizedPeekDN, peekdn, sizedpeekdn};
+pub use peekdn::{End, PeekDN, SizedPeekDN, peekdn, sizedpeekdn};
 
 #[cfg(feature = "peekde")]
 mod peekablede;
 
 #[cfg(feature = "peekde")]
 pub use peekablede::{PeekableDE, peekablede};
+#[cfg(all(feature = "peekde", feature = "alloc"))]
+pub use peekablede::{PeekableDEForward, peekablede_forward};
+
+#[cfg(any(feature = "peekn", feature = "peekdn"))]
+pub mod buffer;
+#[cfg(any(feature = "peekn", feature = "peekdn"))]
+pub use buffer::PeekBuffer;
+
+pub mod error;
+pub use error::{IntoSizedError, PeekError};
+
+/// The largest index accepted by the `peek_nth`, `peek_front_nth` and `peek_back_nth` family.
+///
+/// No iterator can buffer more addressable elements than this, so larger indices
+/// always yield `None`, in both debug and release builds.
+pub const MAX_PEEK_INDEX: usize = usize::MAX - 1;
+
+#[cfg(any(feature = "peekn", feature = "peekdn", feature = "peekde"))]
+mod ext;
+#[cfg(any(feature = "peekdn", feature = "peekde"))]
+pub use ext::DoubleEndedPeekExt;
+#[cfg(feature = "peekn")]
+pub use ext::IteratorPeekExt;
 
 mod util;
 #[cfg(any(feature = "peekde", feature = "peekn"))]
@@ -14,6 +14,7 @@ use crate::PeekDN;
 /// It supports:
 /// - Peeking the next item from the front (`peek_front`)
 /// - Peeking the next item from the back (`peek_back`)
+/// - Peeking up to two items from either end (`peek_front_nth`, `peek_back_nth`)
 /// - Mutable peeking from either end
 /// - Conditional consumption via `next_if`, `next_back_if`, etc.
 /// - Conversions from `PeekN` and `PeekDN` (if features enabled)
@@ -27,31 +28,45 @@ use crate::PeekDN;
 /// assert_eq!(iter.next(), Some(1));
 /// assert_eq!(iter.next_back(), Some(3));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "I: serde::Serialize, I::Item: serde::Serialize",
+        deserialize = "I: serde::Deserialize<'de>, I::Item: serde::Deserialize<'de>"
+    ))
+)]
 pub struct PeekableDE<I: DoubleEndedIterator> {
     pub(crate) iter: I,
     pub(crate) front: Option<Option<I::Item>>,
     pub(crate) back: Option<Option<I::Item>>,
+    /// Second front slot, filled by `peek_front_nth(1)`. Only set while `front` holds an item.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) front_second: Option<I::Item>,
+    /// Second back slot, filled by `peek_back_nth(1)`. Only set while `back` holds an item.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) back_second: Option<I::Item>,
 }
 
 impl<I: DoubleEndedIterator> Iterator for PeekableDE<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.front
-            .take()
-            .flatten()
+        self.take_front()
             .or_else(|| self.iter.next())
-            .or_else(|| self.back.take().flatten())
+            .or_else(|| self.take_back_inner())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.buffered_len())
     }
 }
 
 impl<I: DoubleEndedIterator> DoubleEndedIterator for PeekableDE<I> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.back
-            .take()
-            .flatten()
+        self.take_back()
             .or_else(|| self.iter.next_back())
-            .or_else(|| self.front.take().flatten())
+            .or_else(|| self.take_front_inner())
     }
 }
 
@@ -65,6 +80,8 @@ where
             iter: self.iter.clone(),
             front: self.front.clone(),
             back: self.back.clone(),
+            front_second: self.front_second.clone(),
+            back_second: self.back_second.clone(),
         }
     }
 }
@@ -79,15 +96,15 @@ where
             .field("iter", &self.iter)
             .field("front", &self.front)
             .field("back", &self.back)
+            .field("front_second", &self.front_second)
+            .field("back_second", &self.back_second)
             .finish()
     }
 }
 
 impl<I: ExactSizeIterator + DoubleEndedIterator> ExactSizeIterator for PeekableDE<I> {
     fn len(&self) -> usize {
-        self.iter.len()
-            + matches!(self.front, Some(Some(_))) as usize
-            + matches!(self.back, Some(Some(_))) as usize
+        self.iter.len() + self.buffered_len()
     }
 }
 
@@ -104,7 +121,25 @@ where
     I::Item: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.iter == other.iter && self.front == other.front && self.back == other.back
+        self.iter == other.iter
+            && self.front == other.front
+            && self.back == other.back
+            && self.front_second == other.front_second
+            && self.back_second == other.back_second
+    }
+}
+
+impl<I> core::hash::Hash for PeekableDE<I>
+where
+    I: DoubleEndedIterator + core::hash::Hash,
+    I::Item: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.front.hash(state);
+        self.back.hash(state);
+        self.front_second.hash(state);
+        self.back_second.hash(state);
     }
 }
 
@@ -120,6 +155,8 @@ where
             iter: peekable,
             front,
             back: None,
+            front_second: None,
+            back_second: None,
         }
     }
 }
@@ -138,7 +175,9 @@ impl<I: DoubleEndedIterator> From<PeekN<I>> for PeekableDE<I> {
         PeekableDE {
             iter: peekn.iter,
             front,
-            back: None,
+            back: peekn.tail.map(Some),
+            front_second: None,
+            back_second: None,
         }
     }
 }
@@ -166,12 +205,25 @@ impl<I: DoubleEndedIterator> From<PeekDN<I>> for PeekableDE<I> {
             iter: peekdn.iter,
             front,
             back,
+            front_second: None,
+            back_second: None,
         }
     }
 }
 
 impl<I: FusedIterator + DoubleEndedIterator> FusedIterator for PeekableDE<I> {}
 
+#[cfg(feature = "itertools")]
+impl<I: DoubleEndedIterator> itertools::PeekingNext for PeekableDE<I> {
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(accept)
+    }
+}
+
 impl<I: DoubleEndedIterator> PeekableDE<I> {
     /// Creates a new `PeekableDE` from a double-ended iterator.
     pub fn new(iter: I) -> Self {
@@ -179,6 +231,8 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
             iter,
             front: None,
             back: None,
+            front_second: None,
+            back_second: None,
         }
     }
 
@@ -189,7 +243,9 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         if let Some(item) = self.front.get_or_insert_with(|| self.iter.next()).as_ref() {
             return Some(item);
         }
-        self.back.as_ref().and_then(|b| b.as_ref())
+        self.back_second
+            .as_ref()
+            .or_else(|| self.back.as_ref().and_then(|b| b.as_ref()))
     }
 
     /// Peeks at the next item from the back without consuming it.
@@ -201,7 +257,9 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         {
             return Some(item);
         }
-        self.front.as_ref().and_then(|b| b.as_ref())
+        self.front_second
+            .as_ref()
+            .or_else(|| self.front.as_ref().and_then(|b| b.as_ref()))
     }
 
     /// Peeks at the next item from the front as a mutable reference.
@@ -209,7 +267,10 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         if let Some(item) = self.front.get_or_insert_with(|| self.iter.next()).as_mut() {
             return Some(item);
         }
-        self.back.as_mut().and_then(|b| b.as_mut())
+        match self.back_second {
+            Some(ref mut item) => Some(item),
+            None => self.back.as_mut().and_then(|b| b.as_mut()),
+        }
     }
 
     /// Peeks at the next item from the back as a mutable reference.
@@ -221,7 +282,91 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         {
             return Some(item);
         }
-        self.front.as_mut().and_then(|b| b.as_mut())
+        match self.front_second {
+            Some(ref mut item) => Some(item),
+            None => self.front.as_mut().and_then(|b| b.as_mut()),
+        }
+    }
+
+    /// Peeks at the `n`-th item from the front without consuming it.
+    ///
+    /// `PeekableDE` has a fixed lookahead of **two slots per end**, so only `n` in `0..=1`
+    /// is supported. `peek_front_nth(0)` is the same as [`peek_front`](Self::peek_front).
+    ///
+    /// # Returns
+    /// The item at index `n` from the front, or `None` if it does not exist or `n > 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekablede;
+    ///
+    /// let mut iter = peekablede("==x".chars());
+    /// assert_eq!(iter.peek_front_nth(1), Some(&'='));
+    /// assert_eq!(iter.peek_front_nth(2), None);
+    /// assert_eq!(iter.next(), Some('='));
+    /// ```
+    pub fn peek_front_nth(&mut self, n: usize) -> Option<&I::Item> {
+        match n {
+            0 => self.peek_front(),
+            1 => {
+                self.peek_front()?;
+                if matches!(self.front, Some(Some(_))) && self.front_second.is_none() {
+                    self.front_second = self.iter.next();
+                }
+                [
+                    self.front.as_ref().and_then(|f| f.as_ref()),
+                    self.front_second.as_ref(),
+                    self.back_second.as_ref(),
+                    self.back.as_ref().and_then(|b| b.as_ref()),
+                ]
+                .into_iter()
+                .flatten()
+                .nth(1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Peeks at the `n`-th item from the back without consuming it.
+    ///
+    /// Like [`peek_front_nth`](Self::peek_front_nth), only `n` in `0..=1` is supported.
+    ///
+    /// # Returns
+    /// The item at index `n` from the back, or `None` if it does not exist or `n > 1`.
+    pub fn peek_back_nth(&mut self, n: usize) -> Option<&I::Item> {
+        match n {
+            0 => self.peek_back(),
+            1 => {
+                self.peek_back()?;
+                if matches!(self.back, Some(Some(_))) && self.back_second.is_none() {
+                    self.back_second = self.iter.next_back();
+                }
+                [
+                    self.back.as_ref().and_then(|b| b.as_ref()),
+                    self.back_second.as_ref(),
+                    self.front_second.as_ref(),
+                    self.front.as_ref().and_then(|f| f.as_ref()),
+                ]
+                .into_iter()
+                .flatten()
+                .nth(1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns owned clones of the next front and back items.
+    ///
+    /// Unlike `peek_front` and `peek_back`, the returned values do not borrow `self`,
+    /// so both ends can be held at the same time. If only one item remains, both
+    /// values are clones of it.
+    pub fn snapshot_ends(&mut self) -> (Option<I::Item>, Option<I::Item>)
+    where
+        I::Item: Clone,
+    {
+        let front = self.peek_front().cloned();
+        let back = self.peek_back().cloned();
+        (front, back)
     }
 
     /// Consumes and returns the next front item if it satisfies the predicate.
@@ -274,6 +419,34 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         self.next_back_if(|next| next == expected)
     }
 
+    /// Consumes the next front item only if `func` maps it to `Some`, and returns the mapped value.
+    ///
+    /// When `func` returns `None`, the item is left in place.
+    pub fn next_if_map<R>(&mut self, func: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        let mapped = func(self.peek_front()?)?;
+        self.next();
+        Some(mapped)
+    }
+
+    /// Consumes the next back item only if `func` maps it to `Some`, and returns the mapped value.
+    ///
+    /// When `func` returns `None`, the item is left in place.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekablede;
+    ///
+    /// let mut iter = peekablede(["a", "1"].into_iter());
+    /// assert_eq!(iter.next_back_if_map(|s| s.parse::<u8>().ok()), Some(1));
+    /// assert_eq!(iter.next_back_if_map(|s| s.parse::<u8>().ok()), None);
+    /// assert_eq!(iter.next_back(), Some("a"));
+    /// ```
+    pub fn next_back_if_map<R>(&mut self, func: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        let mapped = func(self.peek_back()?)?;
+        self.next_back();
+        Some(mapped)
+    }
+
     /// Converts this `PeekableDE` into a standard `Peekable`, discarding peek state.
     ///
     /// Any peeked front/back values will be lost.
@@ -298,12 +471,14 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
     #[inline]
     pub fn clear_front_peeked(&mut self) {
         self.front = None;
+        self.front_second = None;
     }
 
     /// Discards the currently peeked back item without consuming it.
     #[inline]
     pub fn clear_back_peeked(&mut self) {
         self.back = None;
+        self.back_second = None;
     }
 
     /// Clears both front and back peeked items, if any, without advancing the iterator.
@@ -349,39 +524,120 @@ impl<I: DoubleEndedIterator> PeekableDE<I> {
         })
     }
 
+    /// Counts how many items from the front satisfy the predicate without consuming them.
+    ///
+    /// Only the next item is examined, so this returns `1` if the next
+    /// front item matches and `0` otherwise. Provided for API symmetry with `PeekDN`.
+    pub fn while_peek_front(&mut self, func: impl FnOnce(&I::Item) -> bool) -> usize {
+        self.peek_front().map_or(0, |item| func(item) as usize)
+    }
+
+    /// Counts how many items from the back satisfy the predicate without consuming them.
+    ///
+    /// Only the next item is examined, so this returns `1` if the next
+    /// back item matches and `0` otherwise. Provided for API symmetry with `PeekDN`.
+    pub fn while_peek_back(&mut self, func: impl FnOnce(&I::Item) -> bool) -> usize {
+        self.peek_back().map_or(0, |item| func(item) as usize)
+    }
+
     fn next_with_source(&mut self) -> Option<PeekSource<I::Item>> {
-        if let Some(front) = self.front.take().flatten() {
+        if let Some(front) = self.take_front() {
             Some(PeekSource::Front(front))
         } else if let Some(iter) = self.iter.next() {
             Some(PeekSource::Iter(iter))
         } else {
-            self.back.take().flatten().map(PeekSource::Back)
+            self.take_back_inner().map(PeekSource::Back)
         }
     }
 
     fn cache_front(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front = Some(Some(front)),
-            PeekSource::Iter(iter) => self.front = Some(Some(iter)),
-            PeekSource::Back(back) => self.back = Some(Some(back)),
+            PeekSource::Front(item) | PeekSource::Iter(item) => self.put_front(item),
+            PeekSource::Back(back) => self.put_back_inner(back),
         }
     }
 
     fn next_back_with_source(&mut self) -> Option<PeekSource<I::Item>> {
-        if let Some(back) = self.back.take().flatten() {
+        if let Some(back) = self.take_back() {
             Some(PeekSource::Back(back))
         } else if let Some(iter) = self.iter.next_back() {
             Some(PeekSource::Iter(iter))
         } else {
-            self.front.take().flatten().map(PeekSource::Front)
+            self.take_front_inner().map(PeekSource::Front)
         }
     }
 
     fn cache_back(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front = Some(Some(front)),
-            PeekSource::Iter(iter) => self.back = Some(Some(iter)),
-            PeekSource::Back(back) => self.back = Some(Some(back)),
+            PeekSource::Front(front) => self.put_front_inner(front),
+            PeekSource::Iter(item) | PeekSource::Back(item) => self.put_back(item),
+        }
+    }
+
+    fn buffered_len(&self) -> usize {
+        matches!(self.front, Some(Some(_))) as usize
+            + matches!(self.back, Some(Some(_))) as usize
+            + self.front_second.is_some() as usize
+            + self.back_second.is_some() as usize
+    }
+
+    /// Takes the outermost front slot, moving the second slot up.
+    fn take_front(&mut self) -> Option<I::Item> {
+        let item = self.front.take().flatten()?;
+        self.front = self.front_second.take().map(Some);
+        Some(item)
+    }
+
+    /// Takes the outermost back slot, moving the second slot up.
+    fn take_back(&mut self) -> Option<I::Item> {
+        let item = self.back.take().flatten()?;
+        self.back = self.back_second.take().map(Some);
+        Some(item)
+    }
+
+    /// Takes the front item closest to the middle of the sequence.
+    fn take_front_inner(&mut self) -> Option<I::Item> {
+        self.front_second
+            .take()
+            .or_else(|| self.front.take().flatten())
+    }
+
+    /// Takes the back item closest to the middle of the sequence.
+    fn take_back_inner(&mut self) -> Option<I::Item> {
+        self.back_second
+            .take()
+            .or_else(|| self.back.take().flatten())
+    }
+
+    /// Restores an item taken by `take_front`.
+    fn put_front(&mut self, item: I::Item) {
+        if let Some(Some(prev)) = self.front.replace(Some(item)) {
+            self.front_second = Some(prev);
+        }
+    }
+
+    /// Restores an item taken by `take_back`.
+    fn put_back(&mut self, item: I::Item) {
+        if let Some(Some(prev)) = self.back.replace(Some(item)) {
+            self.back_second = Some(prev);
+        }
+    }
+
+    /// Restores an item taken by `take_front_inner`.
+    fn put_front_inner(&mut self, item: I::Item) {
+        if matches!(self.front, Some(Some(_))) {
+            self.front_second = Some(item);
+        } else {
+            self.front = Some(Some(item));
+        }
+    }
+
+    /// Restores an item taken by `take_back_inner`.
+    fn put_back_inner(&mut self, item: I::Item) {
+        if matches!(self.back, Some(Some(_))) {
+            self.back_second = Some(item);
+        } else {
+            self.back = Some(Some(item));
         }
     }
 }
@@ -0,0 +1,216 @@
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+
+use core::iter::{FusedIterator, Peekable};
+
+/// `PeekableDEForward` offers the [`PeekableDE`](crate::PeekableDE) peek surface over a
+/// forward-only iterator.
+///
+/// Front peeking streams: it buffers at most the item being peeked. Back queries
+/// (`peek_back`, `next_back`, ...) are answered **eagerly** by draining the entire inner
+/// iterator into an internal buffer, so they must only be used on finite iterators.
+///
+/// This lets generic code target the `PeekableDE` API regardless of whether the source is
+/// double-ended.
+///
+/// # Examples
+/// ```
+/// use peeknth::peekablede_forward;
+///
+/// let mut iter = peekablede_forward((1..=3).filter(|_| true));
+/// assert_eq!(iter.peek_front(), Some(&1));
+/// assert_eq!(iter.peek_back(), Some(&3));
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.next_back(), Some(3));
+/// ```
+pub struct PeekableDEForward<I: Iterator> {
+    pub(crate) iter: I,
+    pub(crate) buffer: VecDeque<I::Item>,
+    pub(crate) drained: bool,
+}
+
+impl<I: Iterator> Iterator for PeekableDEForward<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_front().or_else(|| self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.buffer.len())
+    }
+}
+
+/// `next_back` drains the inner iterator into the buffer on first use.
+impl<I: Iterator> DoubleEndedIterator for PeekableDEForward<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buffer_all();
+        self.buffer.pop_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for PeekableDEForward<I> {
+    fn len(&self) -> usize {
+        self.iter.len() + self.buffer.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for PeekableDEForward<I> {}
+
+impl<I> Clone for PeekableDEForward<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        PeekableDEForward {
+            iter: self.iter.clone(),
+            buffer: self.buffer.clone(),
+            drained: self.drained,
+        }
+    }
+}
+
+impl<I> core::fmt::Debug for PeekableDEForward<I>
+where
+    I: Iterator + core::fmt::Debug,
+    I::Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PeekableDEForward")
+            .field("iter", &self.iter)
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+impl<I: Iterator> PeekableDEForward<I> {
+    /// Creates a new `PeekableDEForward` from a forward-only iterator.
+    pub fn new(iter: I) -> Self {
+        PeekableDEForward {
+            iter,
+            buffer: VecDeque::new(),
+            drained: false,
+        }
+    }
+
+    /// Peeks at the next item from the front without consuming it.
+    ///
+    /// This streams: at most one item is pulled from the inner iterator.
+    pub fn peek_front(&mut self) -> Option<&I::Item> {
+        self.fill_front();
+        self.buffer.front()
+    }
+
+    /// Peeks at the next item from the back without consuming it.
+    ///
+    /// The first call drains the entire inner iterator into the buffer.
+    pub fn peek_back(&mut self) -> Option<&I::Item> {
+        self.buffer_all();
+        self.buffer.back()
+    }
+
+    /// Peeks at the next item from the front as a mutable reference.
+    pub fn peek_front_mut(&mut self) -> Option<&mut I::Item> {
+        self.fill_front();
+        self.buffer.front_mut()
+    }
+
+    /// Peeks at the next item from the back as a mutable reference.
+    ///
+    /// The first call drains the entire inner iterator into the buffer.
+    pub fn peek_back_mut(&mut self) -> Option<&mut I::Item> {
+        self.buffer_all();
+        self.buffer.back_mut()
+    }
+
+    /// Consumes and returns the next front item if it satisfies the predicate.
+    ///
+    /// If the predicate fails, the item is pushed back and preserved.
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next() {
+            Some(matched) if func(&matched) => Some(matched),
+            Some(other) => {
+                self.buffer.push_front(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Consumes and returns the next back item if it satisfies the predicate.
+    ///
+    /// If the predicate fails, the item is pushed back and preserved.
+    pub fn next_back_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next_back() {
+            Some(matched) if func(&matched) => Some(matched),
+            Some(other) => {
+                self.buffer.push_back(other);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Consumes and returns the next front item if it equals `expected`.
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|next| next == expected)
+    }
+
+    /// Consumes and returns the next back item if it equals `expected`.
+    pub fn next_back_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_back_if(|next| next == expected)
+    }
+
+    /// Converts this `PeekableDEForward` into a standard `Peekable`, discarding buffered items.
+    #[inline]
+    pub fn into_peekable_lossy(self) -> Peekable<I> {
+        self.iter.peekable()
+    }
+
+    /// Returns `true` if the inner iterator has been drained to answer a back query.
+    #[inline]
+    pub fn is_fully_buffered(&self) -> bool {
+        self.drained
+    }
+
+    /// Returns the number of items currently buffered.
+    #[inline]
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn fill_front(&mut self) {
+        if self.buffer.is_empty() && !self.drained {
+            self.buffer.extend(self.iter.next());
+        }
+    }
+
+    fn buffer_all(&mut self) {
+        if !self.drained {
+            self.buffer.extend(self.iter.by_ref());
+            self.drained = true;
+        }
+    }
+}
+
+/// A convenient function to wrap a forward-only iterator into `PeekableDEForward`.
+///
+/// # Examples
+/// ```
+/// use peeknth::peekablede_forward;
+/// let mut iter = peekablede_forward("ab".chars());
+/// assert_eq!(iter.peek_back(), Some(&'b'));
+/// ```
+pub fn peekablede_forward<I: Iterator>(iter: I) -> PeekableDEForward<I> {
+    PeekableDEForward::new(iter)
+}
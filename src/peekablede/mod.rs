@@ -0,0 +1,7 @@
+mod core;
+#[cfg(feature = "alloc")]
+mod forward;
+
+pub use core::{PeekableDE, peekablede};
+#[cfg(feature = "alloc")]
+pub use forward::{PeekableDEForward, peekablede_forward};
@@ -3,16 +3,17 @@ use crate::peekn::{PeekN, SizedPeekN};
 
 extern crate alloc;
 
-use alloc::collections::VecDeque;
+use alloc::{collections::VecDeque, vec::Vec};
 
 use core::{
-    iter::{DoubleEndedIterator, FusedIterator, Peekable},
+    iter::{DoubleEndedIterator, FusedIterator, Peekable, Rev},
     ops::RangeBounds,
 };
 
 use crate::{
     SizedPeekDN,
-    util::{Either, PeekSource},
+    peekdn::End,
+    util::{Chain, Either, PeekSource},
 };
 
 #[cfg(feature = "peekde")]
@@ -32,6 +33,12 @@ use crate::peekablede::PeekableDE;
 /// - Conditional consumption (`next_if`, `next_back_if`)
 /// - Supports `From` conversions from other peekable types (e.g. `PeekN`, `PeekableDE`)
 ///
+/// # Exhausting the iterator
+/// A large peek from one end can drain the whole inner iterator into that end's buffer.
+/// The other end still sees every element: once the iterator is empty, `peek_back_nth` reads
+/// from the tail of the front buffer (and `peek_front_nth` from the tail of the back buffer).
+/// [`fully_buffered`](Self::fully_buffered) reports when this is the case.
+///
 /// # Examples
 ///
 /// ```
@@ -49,13 +56,15 @@ pub struct PeekDN<I: DoubleEndedIterator> {
     pub(crate) iter: I,
     pub(crate) front: VecDeque<I::Item>,
     pub(crate) back: VecDeque<I::Item>,
+    /// Set once the inner iterator has returned `None`.
+    pub(crate) exhausted: bool,
 }
 
 impl<I: DoubleEndedIterator> DoubleEndedIterator for PeekDN<I> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.back
             .pop_front()
-            .or_else(|| self.iter.next_back())
+            .or_else(|| self.pull_back())
             .or_else(|| self.front.pop_back())
     }
 }
@@ -66,9 +75,13 @@ impl<I: DoubleEndedIterator> Iterator for PeekDN<I> {
     fn next(&mut self) -> Option<Self::Item> {
         self.front
             .pop_front()
-            .or_else(|| self.iter.next())
+            .or_else(|| self.pull_front())
             .or_else(|| self.back.pop_back())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.front.len() + self.back.len())
+    }
 }
 
 #[cfg(feature = "peekn")]
@@ -77,7 +90,8 @@ impl<I: DoubleEndedIterator> From<PeekN<I>> for PeekDN<I> {
         PeekDN {
             iter: value.iter,
             front: value.buffer,
-            back: VecDeque::new(),
+            back: value.tail.into_iter().collect(),
+            exhausted: false,
         }
     }
 }
@@ -85,7 +99,6 @@ impl<I: DoubleEndedIterator> From<PeekN<I>> for PeekDN<I> {
 impl<I, const S: usize> From<SizedPeekN<I, S>> for PeekDN<I>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     fn from(value: SizedPeekN<I, S>) -> Self {
         let front = VecDeque::from(value.buffer);
@@ -93,6 +106,7 @@ where
             iter: value.iter,
             front,
             back: VecDeque::new(),
+            exhausted: false,
         }
     }
 }
@@ -100,13 +114,13 @@ where
 impl<I, const F: usize, const B: usize> From<SizedPeekDN<I, F, B>> for PeekDN<I>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     fn from(value: SizedPeekDN<I, F, B>) -> Self {
         PeekDN {
             iter: value.iter,
             front: VecDeque::from(value.front),
             back: VecDeque::from(value.back),
+            exhausted: false,
         }
     }
 }
@@ -114,12 +128,23 @@ where
 #[cfg(feature = "peekde")]
 impl<I: DoubleEndedIterator> From<PeekableDE<I>> for PeekDN<I> {
     fn from(value: PeekableDE<I>) -> Self {
-        let front = value.front.flatten().into_iter().collect();
-        let back = value.back.flatten().into_iter().collect();
+        let front = value
+            .front
+            .flatten()
+            .into_iter()
+            .chain(value.front_second)
+            .collect();
+        let back = value
+            .back
+            .flatten()
+            .into_iter()
+            .chain(value.back_second)
+            .collect();
         PeekDN {
             iter: value.iter,
             front,
             back,
+            exhausted: false,
         }
     }
 }
@@ -136,6 +161,7 @@ where
             iter: peekable,
             front,
             back: VecDeque::new(),
+            exhausted: false,
         }
     }
 }
@@ -150,6 +176,7 @@ where
             iter: self.iter.clone(),
             front: Clone::clone(&self.front),
             back: Clone::clone(&self.back),
+            exhausted: self.exhausted,
         }
     }
 }
@@ -191,8 +218,31 @@ where
     }
 }
 
+impl<I> core::hash::Hash for PeekDN<I>
+where
+    I: DoubleEndedIterator + core::hash::Hash,
+    I::Item: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.front.hash(state);
+        self.back.hash(state);
+    }
+}
+
 impl<I: DoubleEndedIterator + FusedIterator> FusedIterator for PeekDN<I> {}
 
+#[cfg(feature = "itertools")]
+impl<I: DoubleEndedIterator> itertools::PeekingNext for PeekDN<I> {
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(accept)
+    }
+}
+
 impl<I: DoubleEndedIterator> PeekDN<I> {
     /// Creates a new `PeekDN` from the given `DoubleEndedIterator`.
     pub fn new(iter: I) -> Self {
@@ -200,6 +250,7 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             iter,
             front: VecDeque::new(),
             back: VecDeque::new(),
+            exhausted: false,
         }
     }
 
@@ -215,6 +266,29 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             iter,
             front: VecDeque::with_capacity(front),
             back: VecDeque::with_capacity(back),
+            exhausted: false,
+        }
+    }
+
+    /// Creates a `PeekDN` from an iterator and preloaded front and back buffers.
+    ///
+    /// The buffers are assumed to logically surround the iterator: `front` precedes it in
+    /// order, and `back` follows it in reverse order, so `back[0]` is the last element of
+    /// the sequence and the first one returned by `next_back`.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::PeekDN;
+    ///
+    /// let iter = PeekDN::from_parts(2..4, [0, 1].into(), [5, 4].into());
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn from_parts(iter: I, front: VecDeque<I::Item>, back: VecDeque<I::Item>) -> Self {
+        PeekDN {
+            iter,
+            front,
+            back,
+            exhausted: false,
         }
     }
 
@@ -236,17 +310,16 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
     /// assert_eq!(iter.peek_front_nth(10), None);
     /// ```
     pub fn peek_front_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_front_nth() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.front.len() > n {
             return self.front.get(n);
         }
 
         while self.front.len() <= n {
-            match self.iter.next() {
+            match self.pull_front() {
                 Some(item) => self.front.push_back(item),
                 None => {
                     return self.back.get((self.back.len() + self.front.len()).checked_sub(n + 1)?);
@@ -257,6 +330,33 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.front.get(n)
     }
 
+    /// Peeks at the element at absolute index `n`, counting from the front.
+    ///
+    /// The logical sequence is the front buffer, then the unread part of the iterator,
+    /// then the back buffer in reverse. Items are pulled into the front buffer as needed;
+    /// once the iterator is exhausted, the two buffers meet and indices past the front
+    /// buffer continue into the back buffer, so `n` can land on an item that was peeked
+    /// from the back.
+    ///
+    /// This is the same lookup as [`peek_front_nth`](Self::peek_front_nth).
+    ///
+    /// # Returns
+    /// The item at index `n`, or `None` if `n` is at or past the number of remaining items.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..=4);
+    /// assert_eq!(iter.peek_back_nth(1), Some(&3));
+    /// assert_eq!(iter.peek_nth(4), Some(&4));
+    /// assert_eq!(iter.peek_nth(5), None);
+    /// ```
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.peek_front_nth(n)
+    }
+
     /// Peeks at the `n`-th element from the back without consuming it.
     ///
     /// Internally fills the back buffer up to index `n` as needed.
@@ -276,22 +376,65 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
     /// assert_eq!(iter.peek_back_nth(5), Some(&0));
     /// ```
     pub fn peek_back_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_back_nth() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.back.len() > n {
             return self.back.get(n);
         }
 
         while self.back.len() <= n {
-            match self.iter.next_back() {
+            match self.pull_back() {
+                Some(item) => self.back.push_back(item),
+                None => {
+                    return self.front.get((self.back.len() + self.front.len()).checked_sub(n + 1)?);
+                }
+            }
+        }
+
+        self.back.get(n)
+    }
+
+    /// Peeks at the `n`-th element from the back, pulling at most `budget` new elements.
+    ///
+    /// Behaves like [`peek_back_nth`](Self::peek_back_nth), but stops after `budget`
+    /// calls to `next_back` so that a very long (or endless) iterator cannot hang.
+    ///
+    /// # Arguments
+    /// * `n` - Index from the back (0 = last element).
+    /// * `budget` - Maximum number of elements to pull from the inner iterator.
+    ///
+    /// # Returns
+    /// `None` if the budget runs out before reaching index `n`,
+    /// or if the iterator ends before reaching it.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..100);
+    ///
+    /// assert_eq!(iter.peek_back_nth_bounded(5, 3), None);
+    /// assert_eq!(iter.peek_back_nth_bounded(5, 3), Some(&94));
+    /// ```
+    pub fn peek_back_nth_bounded(&mut self, n: usize, budget: usize) -> Option<&I::Item> {
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
+
+        let mut pulled = 0;
+        while self.back.len() <= n {
+            if pulled == budget {
+                return None;
+            }
+            match self.pull_back() {
                 Some(item) => self.back.push_back(item),
                 None => {
                     return self.front.get((self.back.len() + self.front.len()).checked_sub(n + 1)?);
                 }
             }
+            pulled += 1;
         }
 
         self.back.get(n)
@@ -299,17 +442,16 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
 
     /// Mutably peeks at the `n`-th element from the front.
     pub fn peek_front_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_front_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.front.len() > n {
             return self.front.get_mut(n);
         }
 
         while self.front.len() <= n {
-            match self.iter.next() {
+            match self.pull_front() {
                 Some(item) => self.front.push_back(item),
                 None => {
                     return self
@@ -324,17 +466,16 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
 
     /// Mutably peeks at the `n`-th element from the back.
     pub fn peek_back_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_back_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.back.len() > n {
             return self.back.get_mut(n);
         }
 
         while self.back.len() <= n {
-            match self.iter.next_back() {
+            match self.pull_back() {
                 Some(item) => self.back.push_back(item),
                 None => {
                     return self
@@ -367,13 +508,36 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.peek_back_nth_mut(0)
     }
 
+    /// Returns owned clones of the next front and back items.
+    ///
+    /// Unlike `peek_front` and `peek_back`, the returned values do not borrow `self`,
+    /// so both ends can be held at the same time. If only one item remains, both
+    /// values are clones of it.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(1..=5);
+    /// assert_eq!(iter.snapshot_ends(), (Some(1), Some(5)));
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn snapshot_ends(&mut self) -> (Option<I::Item>, Option<I::Item>)
+    where
+        I::Item: Clone,
+    {
+        let front = self.peek_front().cloned();
+        let back = self.peek_back().cloned();
+        (front, back)
+    }
+
     /// Peeks at a range of elements from the front.
     ///
     /// Fills the front buffer as needed. The range is inclusive-exclusive (`start..end`).
     pub fn peek_front_range<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &I::Item>
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + ExactSizeIterator + Clone
     where
         I: ExactSizeIterator,
     {
@@ -390,11 +554,8 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             return Either::Single(self.front.range(0..0));
         }
 
-        let mut actual_end = 0;
-        for i in 0..end {
-            if self.peek_front_nth(i).is_none() {
-                break;
-            }
+        let mut actual_end = start;
+        while actual_end < end && self.peek_front_nth(actual_end).is_some() {
             actual_end += 1;
         }
 
@@ -402,20 +563,20 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         if actual_end <= len {
             Either::Single(self.front.range(start..actual_end))
         } else {
-            let back = self.back.len();
-            let from = (back + len).saturating_sub(actual_end);
-            Either::Chain(
-                self.front
-                    .range(start..len)
-                    .chain(self.back.range(from..back).rev()),
-            )
+            let total = self.back.len() + len;
+            Either::Chain(Chain::new(
+                self.front.range(start.min(len)..len),
+                self.back
+                    .range(total - actual_end..total - start.max(len))
+                    .rev(),
+            ))
         }
     }
 
     pub fn peek_front_range_mut<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &mut I::Item>
+    ) -> impl DoubleEndedIterator<Item = &mut I::Item> + ExactSizeIterator
     where
         I: ExactSizeIterator,
     {
@@ -432,11 +593,8 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             return Either::Single(self.front.range_mut(0..0));
         }
 
-        let mut actual_end = 0;
-        for i in start..end {
-            if self.peek_front_nth(i).is_none() {
-                break;
-            }
+        let mut actual_end = start;
+        while actual_end < end && self.peek_front_nth(actual_end).is_some() {
             actual_end += 1;
         }
 
@@ -444,13 +602,58 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         if actual_end <= len {
             Either::Single(self.front.range_mut(start..actual_end))
         } else {
-            let back = self.back.len();
-            let from = (back + len).saturating_sub(actual_end);
-            Either::Chain(
-                self.front
-                    .range_mut(start..len)
-                    .chain(self.back.range_mut(from..back).rev()),
-            )
+            let total = self.back.len() + len;
+            Either::Chain(Chain::new(
+                self.front.range_mut(start.min(len)..len),
+                self.back
+                    .range_mut(total - actual_end..total - start.max(len))
+                    .rev(),
+            ))
+        }
+    }
+
+    /// Mutably peeks at a range of the front buffer as a pair of slices.
+    ///
+    /// Fills the front buffer up to the end of the range, then returns the requested items as
+    /// the two contiguous segments of the underlying `VecDeque`, in order. The second slice is
+    /// empty unless the range spans the wrap-around point of the buffer.
+    ///
+    /// An unbounded end refers to the currently front-buffered items. Items that are only
+    /// reachable through the back buffer (once the inner iterator is exhausted) are not included.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// let (head, tail) = iter.peek_front_slice_mut(1..4);
+    /// for x in head.iter_mut().chain(tail) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 10, 20, 30, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn peek_front_slice_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> (&mut [I::Item], &mut [I::Item]) {
+        use crate::get_start_end;
+        let (start, end) = get_start_end(range, self.front.len());
+
+        if end > 0 {
+            let _ = self.peek_front_nth(end - 1);
+        }
+
+        let end = end.min(self.front.len());
+        let start = start.min(end);
+        let (head, tail) = self.front.as_mut_slices();
+        let split = head.len();
+
+        if end <= split {
+            (&mut head[start..end], &mut [])
+        } else if start >= split {
+            (&mut tail[start - split..end - split], &mut [])
+        } else {
+            (&mut head[start..], &mut tail[..end - split])
         }
     }
 
@@ -460,7 +663,7 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
     pub fn peek_back_range<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &I::Item>
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + ExactSizeIterator + Clone
     where
         I: ExactSizeIterator,
     {
@@ -477,11 +680,8 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             return Either::Single(self.back.range(0..0));
         }
 
-        let mut actual_end = 0;
-        for i in start..end {
-            if self.peek_back_nth(i).is_none() {
-                break;
-            }
+        let mut actual_end = start;
+        while actual_end < end && self.peek_back_nth(actual_end).is_some() {
             actual_end += 1;
         }
 
@@ -489,20 +689,20 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         if actual_end <= len {
             Either::Single(self.back.range(start..actual_end))
         } else {
-            let front = self.front.len();
-            let from = (front + len).saturating_sub(actual_end);
-            Either::Chain(
-                self.back
-                    .range(start..len)
-                    .chain(self.front.range(from..front).rev()),
-            )
+            let total = self.front.len() + len;
+            Either::Chain(Chain::new(
+                self.back.range(start.min(len)..len),
+                self.front
+                    .range(total - actual_end..total - start.max(len))
+                    .rev(),
+            ))
         }
     }
 
     pub fn peek_back_range_mut<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &mut I::Item>
+    ) -> impl DoubleEndedIterator<Item = &mut I::Item> + ExactSizeIterator
     where
         I: ExactSizeIterator,
     {
@@ -519,11 +719,8 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
             return Either::Single(self.back.range_mut(0..0));
         }
 
-        let mut actual_end = 0;
-        for i in start..end {
-            if self.peek_back_nth(i).is_none() {
-                break;
-            }
+        let mut actual_end = start;
+        while actual_end < end && self.peek_back_nth(actual_end).is_some() {
             actual_end += 1;
         }
 
@@ -531,13 +728,13 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         if actual_end <= len {
             Either::Single(self.back.range_mut(start..actual_end))
         } else {
-            let front = self.front.len();
-            let from = (front + len).saturating_sub(actual_end);
-            Either::Chain(
-                self.back
-                    .range_mut(start..len)
-                    .chain(self.front.range_mut(from..front).rev()),
-            )
+            let total = self.front.len() + len;
+            Either::Chain(Chain::new(
+                self.back.range_mut(start.min(len)..len),
+                self.front
+                    .range_mut(total - actual_end..total - start.max(len))
+                    .rev(),
+            ))
         }
     }
 
@@ -573,6 +770,34 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         }
     }
 
+    /// Consumes an item from whichever end satisfies its predicate, preferring the front.
+    ///
+    /// The next front item is tested against `front_pred` first. If it does not match, the next
+    /// back item is tested against `back_pred`. Items that fail their predicate are pushed back.
+    ///
+    /// # Returns
+    /// The matched item together with the [`End`] it was taken from, or `None` if neither matches.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::{End, peekdn};
+    ///
+    /// let mut iter = peekdn(0..=9);
+    /// assert_eq!(iter.next_if_either(|&x| x == 0, |&x| x == 9), Some((End::Front, 0)));
+    /// assert_eq!(iter.next_if_either(|&x| x == 0, |&x| x == 9), Some((End::Back, 9)));
+    /// assert_eq!(iter.next_if_either(|&x| x == 0, |&x| x == 9), None);
+    /// ```
+    pub fn next_if_either(
+        &mut self,
+        front_pred: impl FnOnce(&I::Item) -> bool,
+        back_pred: impl FnOnce(&I::Item) -> bool,
+    ) -> Option<(End, I::Item)> {
+        if let Some(item) = self.next_if(front_pred) {
+            return Some((End::Front, item));
+        }
+        self.next_back_if(back_pred).map(|item| (End::Back, item))
+    }
+
     /// Consumes and returns the next front item if it equals `expected`.
     ///
     /// Otherwise, the item is pushed back to the buffer.
@@ -593,6 +818,96 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.next_back_if(|next| next == expected)
     }
 
+    /// Consumes the next front item only if `func` maps it to `Some`, and returns the mapped value.
+    ///
+    /// When `func` returns `None`, the item is left in place.
+    pub fn next_if_map<R>(&mut self, func: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        let mapped = func(self.peek_front()?)?;
+        self.next();
+        Some(mapped)
+    }
+
+    /// Consumes the next back item only if `func` maps it to `Some`, and returns the mapped value.
+    ///
+    /// When `func` returns `None`, the item is left in place.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(["a", "1"].into_iter());
+    /// assert_eq!(iter.next_back_if_map(|s| s.parse::<u8>().ok()), Some(1));
+    /// assert_eq!(iter.next_back_if_map(|s| s.parse::<u8>().ok()), None);
+    /// assert_eq!(iter.next_back(), Some("a"));
+    /// ```
+    pub fn next_back_if_map<R>(&mut self, func: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        let mapped = func(self.peek_back()?)?;
+        self.next_back();
+        Some(mapped)
+    }
+
+    /// Consumes items from the front until `f` maps one of them to `Some`, and returns that value.
+    ///
+    /// Every item up to and including the match is consumed; items mapped to `None` are
+    /// discarded. The remaining items, including any back-peeked ones, are left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.find_map_front(|&x| (x * x > 10).then_some(x * x)), Some(16));
+    /// assert_eq!(iter.next(), Some(5));
+    /// ```
+    pub fn find_map_front<R>(&mut self, mut f: impl FnMut(&I::Item) -> Option<R>) -> Option<R> {
+        self.by_ref().find_map(|item| f(&item))
+    }
+
+    /// Consumes items from the back until one satisfies `pred`, and returns it.
+    ///
+    /// This is the back-oriented `find`: every item from the back up to and including the
+    /// match is consumed, and non-matching items are discarded rather than cached. Returns
+    /// `None` once the iterator is exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.find_back(|&x| x % 4 == 0), Some(8));
+    /// assert_eq!(iter.next_back(), Some(7));
+    /// ```
+    pub fn find_back<P>(&mut self, mut pred: P) -> Option<I::Item>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.rfind(|item| pred(item))
+    }
+
+    /// Pushes `item` back to the front, so the next `next()` or `peek_front()` returns it.
+    ///
+    /// Multiple calls stack, so the last item put back is returned first.
+    pub fn put_back_front(&mut self, item: I::Item) {
+        self.front.push_front(item);
+    }
+
+    /// Pushes `item` back to the back, so the next `next_back()` or `peek_back()` returns it.
+    ///
+    /// Multiple calls stack, so the last item put back is returned first.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(1..3);
+    /// iter.put_back_front(0);
+    /// iter.put_back_back(3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn put_back_back(&mut self, item: I::Item) {
+        self.back.push_front(item);
+    }
+
     /// Converts `PeekDN` into a standard `Peekable`, discarding peeked items.
     ///
     /// This is a lossy conversion.
@@ -600,6 +915,40 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.into_iter().peekable()
     }
 
+    /// Splits this `PeekDN` into the inner iterator and the front and back buffers.
+    ///
+    /// No items are lost; see [`from_parts`](Self::from_parts) for the buffer order.
+    pub fn into_parts(self) -> (I, VecDeque<I::Item>, VecDeque<I::Item>) {
+        (self.iter, self.front, self.back)
+    }
+
+    /// Reverses the direction of iteration while keeping all peeked items.
+    ///
+    /// Unlike [`Iterator::rev`], the result is still a `PeekDN`, so `peek_front_nth` refers
+    /// to the front of the reversed sequence. The back buffer becomes the front one and
+    /// vice versa; since each buffer is ordered from its own end inwards, the items keep
+    /// their positions in the reversed sequence.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..=5);
+    /// assert_eq!(iter.peek_front(), Some(&0));
+    /// let mut rev = iter.reversed();
+    /// assert_eq!(rev.peek_front(), Some(&5));
+    /// assert_eq!(rev.peek_back(), Some(&0));
+    /// assert_eq!(rev.collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+    /// ```
+    pub fn reversed(self) -> PeekDN<Rev<I>> {
+        PeekDN {
+            iter: self.iter.rev(),
+            front: self.back,
+            back: self.front,
+            exhausted: self.exhausted,
+        }
+    }
+
     /// Clears all front-peeked elements.
     #[inline]
     pub fn clear_front_peeked(&mut self) {
@@ -619,6 +968,54 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.clear_back_peeked();
     }
 
+    /// Returns the number of items the front peek buffer can hold without reallocating.
+    #[inline]
+    pub fn front_peeked_capacity(&self) -> usize {
+        self.front.capacity()
+    }
+
+    /// Returns the number of items the back peek buffer can hold without reallocating.
+    #[inline]
+    pub fn back_peeked_capacity(&self) -> usize {
+        self.back.capacity()
+    }
+
+    /// Reserves room for at least `additional` more front-peeked items.
+    ///
+    /// See [`VecDeque::reserve`].
+    #[inline]
+    pub fn reserve_front(&mut self, additional: usize) {
+        self.front.reserve(additional);
+    }
+
+    /// Reserves room for at least `additional` more back-peeked items.
+    ///
+    /// See [`VecDeque::reserve`].
+    #[inline]
+    pub fn reserve_back(&mut self, additional: usize) {
+        self.back.reserve(additional);
+    }
+
+    /// Shrinks the front peek buffer's capacity as much as possible.
+    #[inline]
+    pub fn shrink_front(&mut self) {
+        self.front.shrink_to_fit();
+    }
+
+    /// Shrinks the back peek buffer's capacity as much as possible.
+    #[inline]
+    pub fn shrink_back(&mut self) {
+        self.back.shrink_to_fit();
+    }
+
+    /// Shrinks both peek buffers' capacities as much as possible, releasing memory left
+    /// over from a deep peek.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_front();
+        self.shrink_back();
+    }
+
     /// Returns the number of items in the front peek buffer.
     #[inline]
     pub fn front_peeked_len(&self) -> usize {
@@ -643,6 +1040,28 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.back_peeked_len() > n
     }
 
+    /// Returns `true` if the inner iterator is known to be exhausted.
+    ///
+    /// In that case the front and back buffers together hold every remaining element,
+    /// and peeks from either end are served from them. This is the case once the inner
+    /// iterator has returned `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..5);
+    /// assert!(!iter.fully_buffered());
+    ///
+    /// iter.peek_front_nth(10);
+    /// assert!(iter.fully_buffered());
+    /// assert_eq!(iter.peek_back_nth(0), Some(&4));
+    /// ```
+    #[inline]
+    pub fn fully_buffered(&self) -> bool {
+        self.exhausted
+    }
+
     /// Removes up to `until` items from the front peek buffer.
     #[inline]
     pub fn drain_front_peeked(&mut self, until: usize) {
@@ -669,6 +1088,45 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.back.drain(..until);
     }
 
+    /// Removes up to `until` items from the front peek buffer and yields them in order.
+    ///
+    /// Unlike [`drain_front_peeked`](Self::drain_front_peeked), the removed items are
+    /// returned instead of dropped. `until` is clamped to the front buffer length.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..5);
+    /// let _ = iter.peek_front_nth(2);
+    /// assert_eq!(iter.drain_front_peeked_iter(5).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn drain_front_peeked_iter(&mut self, until: usize) -> impl Iterator<Item = I::Item> + '_ {
+        let until = until.min(self.front.len());
+        self.front.drain(..until)
+    }
+
+    /// Removes up to `until` items from the back peek buffer and yields them.
+    ///
+    /// Items come out from the back towards the front, in the same order as
+    /// [`peek_back_nth`](Self::peek_back_nth) indexes them. `until` is clamped to the back
+    /// buffer length.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..5);
+    /// let _ = iter.peek_back_nth(1);
+    /// assert_eq!(iter.drain_back_peeked_iter(5).collect::<Vec<_>>(), vec![4, 3]);
+    /// assert_eq!(iter.next_back(), Some(2));
+    /// ```
+    pub fn drain_back_peeked_iter(&mut self, until: usize) -> impl Iterator<Item = I::Item> + '_ {
+        let until = until.min(self.back.len());
+        self.back.drain(..until)
+    }
+
     /// Drains both front and back peek buffers up to the given limits.
     #[inline]
     pub fn drain_peeked(&mut self, front_until: usize, back_until: usize) {
@@ -676,6 +1134,37 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         self.drain_back_peeked(back_until);
     }
 
+    /// Moves up to `n` items from the end of the front buffer into the back buffer.
+    ///
+    /// The moved items keep their relative order and are placed directly after the
+    /// remaining items of the inner iterator, ahead of anything already peeked from the
+    /// back. Since the back buffer is stored in `next_back` order, the last front-peeked
+    /// item becomes the first one `next_back` yields once the existing back buffer and the
+    /// inner iterator are drained.
+    ///
+    /// If the inner iterator is already exhausted, the logical sequence is unchanged;
+    /// only which buffer holds the items differs.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..5);
+    /// let _ = iter.peek_front_nth(4);
+    /// iter.rotate_front_to_back(2);
+    /// assert_eq!(iter.front_peeked_len(), 3);
+    /// assert_eq!(iter.back_peeked_len(), 2);
+    /// assert_eq!(iter.next_back(), Some(4));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// ```
+    pub fn rotate_front_to_back(&mut self, n: usize) {
+        for _ in 0..n.min(self.front.len()) {
+            if let Some(item) = self.front.pop_back() {
+                self.back.push_back(item);
+            }
+        }
+    }
+
     /// Consumes and yields items from the front while the predicate returns `true`.
     ///
     /// This method repeatedly calls `next()` and yields the item if the predicate returns `true`.
@@ -732,6 +1221,31 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         })
     }
 
+    /// Consumes and collects the leading front items that satisfy the predicate.
+    ///
+    /// This is the eager counterpart of [`while_next_front`](Self::while_next_front): the
+    /// first non-matching item stays buffered, and the borrow ends when this returns.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.split_front_while(|&x| x < 3), vec![0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn split_front_while(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Vec<I::Item> {
+        self.while_next_front(pred).collect()
+    }
+
+    /// Consumes and collects the trailing back items that satisfy the predicate.
+    ///
+    /// The items are in the order they were taken, from the back towards the front. The
+    /// first non-matching item stays buffered.
+    pub fn split_back_while(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Vec<I::Item> {
+        self.while_next_back(pred).collect()
+    }
+
     /// Counts how many items from the front satisfy the predicate without consuming them.
     ///
     /// Peeks the `n`-th front element repeatedly and stops when the predicate returns `false`
@@ -780,10 +1294,121 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
         count
     }
 
+    /// Finds the first item from the front matching `pred` without consuming anything.
+    ///
+    /// # Returns
+    /// The zero-based front index and a reference to the matching item, or `None` if no
+    /// item matches.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.peek_front_find(|&x| x > 2), Some((3, &3)));
+    /// assert_eq!(iter.peek_back_find(|&x| x < 7), Some((3, &6)));
+    /// assert_eq!(iter.len(), 10);
+    /// ```
+    pub fn peek_front_find(
+        &mut self,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> Option<(usize, &I::Item)> {
+        let n = self.while_peek_front(|item| !pred(item));
+        self.peek_front_nth(n).map(|item| (n, item))
+    }
+
+    /// Finds the first item from the back matching `pred` without consuming anything.
+    ///
+    /// # Returns
+    /// The zero-based back index and a reference to the matching item, or `None` if no
+    /// item matches.
+    pub fn peek_back_find(
+        &mut self,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> Option<(usize, &I::Item)> {
+        let n = self.while_peek_back(|item| !pred(item));
+        self.peek_back_nth(n).map(|item| (n, item))
+    }
+
+    /// Returns the zero-based front index of the first item matching `pred`, without
+    /// consuming anything.
+    ///
+    /// Like [`peek_front_find`](Self::peek_front_find), but holds no borrow afterwards; the
+    /// scanned items stay buffered.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.peek_front_position(|&x| x > 2), Some(3));
+    /// assert_eq!(iter.peek_back_position(|&x| x < 7), Some(3));
+    /// assert_eq!(iter.peek_front_position(|&x| x > 20), None);
+    /// ```
+    pub fn peek_front_position(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Option<usize> {
+        self.peek_front_find(pred).map(|(n, _)| n)
+    }
+
+    /// Returns the zero-based back index of the first item from the back matching `pred`,
+    /// without consuming anything.
+    pub fn peek_back_position(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Option<usize> {
+        self.peek_back_find(pred).map(|(n, _)| n)
+    }
+
+    /// Clones the matching prefix from the front into a `Vec`, leaving it buffered.
+    ///
+    /// Works like [`while_peek_front`](Self::while_peek_front), but also returns copies of
+    /// the matching items, so no borrow of `self` is held afterwards.
+    ///
+    /// # Arguments
+    /// * `func` - A predicate to test each peeked item.
+    ///
+    /// # Returns
+    /// The leading items that satisfy the predicate, in order.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::peekdn;
+    ///
+    /// let mut iter = peekdn(0..10);
+    /// assert_eq!(iter.peek_while_vec(|&x| x < 3), vec![0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(0));
+    /// ```
+    pub fn peek_while_vec(&mut self, mut func: impl FnMut(&I::Item) -> bool) -> Vec<I::Item>
+    where
+        I::Item: Clone,
+    {
+        let mut items = Vec::new();
+
+        while let Some(item) = self.peek_front_nth(items.len()) {
+            if func(item) {
+                items.push(item.clone());
+            } else {
+                break;
+            }
+        }
+
+        items
+    }
+
+    /// Pulls the next item from the front of the inner iterator, noting exhaustion.
+    fn pull_front(&mut self) -> Option<I::Item> {
+        let item = self.iter.next();
+        self.exhausted |= item.is_none();
+        item
+    }
+
+    /// Pulls the next item from the back of the inner iterator, noting exhaustion.
+    fn pull_back(&mut self) -> Option<I::Item> {
+        let item = self.iter.next_back();
+        self.exhausted |= item.is_none();
+        item
+    }
+
     fn next_with_source(&mut self) -> Option<PeekSource<I::Item>> {
         if let Some(front) = self.front.pop_front() {
             Some(PeekSource::Front(front))
-        } else if let Some(iter) = self.iter.next() {
+        } else if let Some(iter) = self.pull_front() {
             Some(PeekSource::Iter(iter))
         } else {
             self.back.pop_back().map(PeekSource::Back)
@@ -792,16 +1417,16 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
 
     fn cache_front(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front.push_back(front),
+            PeekSource::Front(front) => self.front.push_front(front),
             PeekSource::Iter(iter) => self.front.push_back(iter),
-            PeekSource::Back(back) => self.back.push_front(back),
+            PeekSource::Back(back) => self.back.push_back(back),
         }
     }
 
     fn next_back_with_source(&mut self) -> Option<PeekSource<I::Item>> {
         if let Some(back) = self.back.pop_front() {
             Some(PeekSource::Back(back))
-        } else if let Some(iter) = self.iter.next_back() {
+        } else if let Some(iter) = self.pull_back() {
             Some(PeekSource::Iter(iter))
         } else {
             self.front.pop_back().map(PeekSource::Front)
@@ -810,9 +1435,9 @@ impl<I: DoubleEndedIterator> PeekDN<I> {
 
     fn cache_back(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front.push_front(front),
+            PeekSource::Front(front) => self.front.push_back(front),
             PeekSource::Iter(iter) => self.back.push_back(iter),
-            PeekSource::Back(back) => self.back.push_back(back),
+            PeekSource::Back(back) => self.back.push_front(back),
         }
     }
 }
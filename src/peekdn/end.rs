@@ -0,0 +1,8 @@
+/// Identifies which end of a double-ended peeker an item was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum End {
+    /// The front of the iterator (as yielded by `next`).
+    Front,
+    /// The back of the iterator (as yielded by `next_back`).
+    Back,
+}
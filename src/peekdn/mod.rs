@@ -1,9 +1,11 @@
 #[cfg(feature = "alloc")]
 mod core;
 
+mod end;
 mod sizedpeekdn;
 
 #[cfg(feature = "alloc")]
 pub use core::{PeekDN, peekdn};
 
+pub use end::End;
 pub use sizedpeekdn::{SizedPeekDN, sizedpeekdn};
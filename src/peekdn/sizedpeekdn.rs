@@ -1,3 +1,4 @@
+use crate::PeekBuffer;
 use crate::util::{Buffer, Either, PeekSource};
 
 use core::{
@@ -8,6 +9,12 @@ use core::{
 #[cfg(feature = "peekde")]
 use crate::peekablede::PeekableDE;
 
+#[cfg(feature = "alloc")]
+use crate::peekdn::PeekDN;
+
+#[cfg(any(feature = "alloc", feature = "peekde"))]
+use crate::{IntoSizedError, PeekError};
+
 #[cfg(feature = "peekn")]
 use crate::peekn::SizedPeekN;
 
@@ -22,14 +29,22 @@ use crate::peekn::SizedPeekN;
 ///
 /// # Type Parameters
 /// - `I`: A double-ended iterator.
-/// - `I::Item`: Must implement `Copy`, since items are stored by value in the buffer.
+/// - `I::Item`: Stored by value in the buffer; buffered items are dropped along with the adapter.
 /// - `F`: Maximum number of front items that can be peeked.
 /// - `B`: Maximum number of back items that can be peeked.
+/// - `FB`, `BB`: The [`PeekBuffer`]s storing the front and back items. They default to inline
+///   ring buffers of capacity `F` and `B`.
 ///
 /// # Panics
 /// Peeking past `F` front items or `B` back items using `peek_nth`, `peek_range`, or their
 /// mutable equivalents will panic.
 ///
+/// # Buffer spill
+/// Once the inner iterator is exhausted, a front peek past the front buffer is served from
+/// the back buffer (and vice versa), so the returned reference may live in the other buffer.
+/// Such a peek does not use front capacity; see
+/// [`front_peek_spilled_to_back`](Self::front_peek_spilled_to_back).
+///
 /// # Example
 /// ```rust
 /// use peeknth::sizedpeekdn;
@@ -37,20 +52,26 @@ use crate::peekn::SizedPeekN;
 /// assert_eq!(peekdn.peek_front_nth(1), Some(&2));
 /// assert_eq!(peekdn.peek_back_nth(1), Some(&4));
 /// ```
-pub struct SizedPeekDN<I, const F: usize, const B: usize>
-where
+pub struct SizedPeekDN<
+    I,
+    const F: usize,
+    const B: usize,
+    FB = Buffer<<I as Iterator>::Item, F>,
+    BB = Buffer<<I as Iterator>::Item, B>,
+> where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     pub(crate) iter: I,
-    pub(crate) front: Buffer<I::Item, F>,
-    pub(crate) back: Buffer<I::Item, B>,
+    pub(crate) front: FB,
+    pub(crate) back: BB,
+    pub(crate) front_spilled: bool,
 }
 
-impl<I, const F: usize, const B: usize> DoubleEndedIterator for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> DoubleEndedIterator for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
+    FB: PeekBuffer<I::Item>,
+    BB: PeekBuffer<I::Item>,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.back
@@ -60,10 +81,11 @@ where
     }
 }
 
-impl<I, const F: usize, const B: usize> Iterator for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> Iterator for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
+    FB: PeekBuffer<I::Item>,
+    BB: PeekBuffer<I::Item>,
 {
     type Item = I::Item;
 
@@ -73,44 +95,121 @@ where
             .or_else(|| self.iter.next())
             .or_else(|| self.back.pop_back())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.front.len() + self.back.len())
+    }
 }
 
 #[cfg(feature = "peekn")]
 impl<I, const F: usize, const B: usize> From<SizedPeekN<I, F>> for SizedPeekDN<I, F, B>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     fn from(value: SizedPeekN<I, F>) -> Self {
         SizedPeekDN {
             iter: value.iter,
             front: value.buffer,
             back: Buffer::new(),
+            front_spilled: false,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<I, const F: usize, const B: usize> TryFrom<PeekDN<I>> for SizedPeekDN<I, F, B>
+where
+    I: DoubleEndedIterator,
+{
+    type Error = IntoSizedError<PeekDN<I>>;
+
+    /// Moves the peeked items of a `PeekDN` into fixed-size buffers.
+    ///
+    /// Fails with [`PeekError::BufferOverflow`], handing back the original `PeekDN`
+    /// unchanged, if more than `F` items are peeked from the front or more than `B` from
+    /// the back.
+    fn try_from(value: PeekDN<I>) -> Result<Self, Self::Error> {
+        let overflow = if value.front.len() > F {
+            Some(F)
+        } else if value.back.len() > B {
+            Some(B)
+        } else {
+            None
+        };
+        if let Some(capacity) = overflow {
+            return Err(IntoSizedError {
+                source: value,
+                error: PeekError::BufferOverflow { capacity },
+            });
+        }
+
+        Ok(SizedPeekDN {
+            iter: value.iter,
+            front: Buffer::from_iter(value.front),
+            back: Buffer::from_iter(value.back),
+            front_spilled: false,
+        })
+    }
+}
+
 #[cfg(feature = "peekde")]
-impl<I, const F: usize, const B: usize> From<PeekableDE<I>> for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize> TryFrom<PeekableDE<I>> for SizedPeekDN<I, F, B>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
-    fn from(peekable_de: PeekableDE<I>) -> Self {
-        let front = Buffer::from_iter(peekable_de.front.flatten());
-        let back = Buffer::from_iter(peekable_de.back.flatten());
-        SizedPeekDN {
+    type Error = IntoSizedError<PeekableDE<I>>;
+
+    /// Moves the peeked slots of each end into the fixed-size buffers.
+    ///
+    /// A `PeekableDE` holds up to two items per end. Fails with
+    /// [`PeekError::BufferOverflow`], handing back the original `PeekableDE` unchanged, if
+    /// an end holds more peeked items than its capacity (`F` or `B`).
+    fn try_from(peekable_de: PeekableDE<I>) -> Result<Self, Self::Error> {
+        let front_len = matches!(peekable_de.front, Some(Some(_))) as usize
+            + peekable_de.front_second.is_some() as usize;
+        let back_len = matches!(peekable_de.back, Some(Some(_))) as usize
+            + peekable_de.back_second.is_some() as usize;
+        let overflow = if front_len > F {
+            Some(F)
+        } else if back_len > B {
+            Some(B)
+        } else {
+            None
+        };
+        if let Some(capacity) = overflow {
+            return Err(IntoSizedError {
+                source: peekable_de,
+                error: PeekError::BufferOverflow { capacity },
+            });
+        }
+
+        let front = Buffer::from_iter(
+            peekable_de
+                .front
+                .flatten()
+                .into_iter()
+                .chain(peekable_de.front_second),
+        );
+        let back = Buffer::from_iter(
+            peekable_de
+                .back
+                .flatten()
+                .into_iter()
+                .chain(peekable_de.back_second),
+        );
+        Ok(SizedPeekDN {
             iter: peekable_de.iter,
             front,
             back,
-        }
+            front_spilled: false,
+        })
     }
 }
 
 impl<I, const F: usize, const B: usize> From<Peekable<I>> for SizedPeekDN<Peekable<I>, F, B>
 where
     I: DoubleEndedIterator,
-    I::Item: Clone + Copy,
+    I::Item: Clone,
 {
     fn from(mut peekable: Peekable<I>) -> Self {
         let front = Buffer::from_iter(peekable.peek().cloned());
@@ -119,28 +218,32 @@ where
             iter: peekable,
             front,
             back: Buffer::new(),
+            front_spilled: false,
         }
     }
 }
 
-impl<I, const B: usize, const F: usize> Clone for SizedPeekDN<I, B, F>
+impl<I, const B: usize, const F: usize, FB, BB> Clone for SizedPeekDN<I, B, F, FB, BB>
 where
     I: DoubleEndedIterator + Clone,
-    I::Item: Copy + Clone,
+    FB: PeekBuffer<I::Item> + Clone,
+    BB: PeekBuffer<I::Item> + Clone,
 {
     fn clone(&self) -> Self {
         SizedPeekDN {
             iter: self.iter.clone(),
             front: Clone::clone(&self.front),
             back: Clone::clone(&self.back),
+            front_spilled: self.front_spilled,
         }
     }
 }
 
-impl<I, const F: usize, const B: usize> core::fmt::Debug for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> core::fmt::Debug for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator + core::fmt::Debug,
-    I::Item: core::fmt::Debug + Copy,
+    FB: PeekBuffer<I::Item> + core::fmt::Debug,
+    BB: PeekBuffer<I::Item> + core::fmt::Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SizedPeekDN")
@@ -151,49 +254,82 @@ where
     }
 }
 
-impl<I, const F: usize, const B: usize> ExactSizeIterator for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> ExactSizeIterator for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator + ExactSizeIterator,
-    I::Item: Copy,
+    FB: PeekBuffer<I::Item>,
+    BB: PeekBuffer<I::Item>,
 {
     fn len(&self) -> usize {
         self.iter.len() + self.front.len() + self.back.len()
     }
 }
-impl<I, const F: usize, const B: usize> FusedIterator for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> FusedIterator for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator + FusedIterator,
-    I::Item: Copy,
+    FB: PeekBuffer<I::Item>,
+    BB: PeekBuffer<I::Item>,
 {
 }
 
-impl<I, const F: usize, const B: usize> Eq for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> Eq for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator + Eq,
-    I::Item: Eq + Copy,
+    FB: PeekBuffer<I::Item> + Eq,
+    BB: PeekBuffer<I::Item> + Eq,
 {
 }
 
-impl<I, const F: usize, const B: usize> PartialEq for SizedPeekDN<I, F, B>
+impl<I, const F: usize, const B: usize, FB, BB> PartialEq for SizedPeekDN<I, F, B, FB, BB>
 where
     I: DoubleEndedIterator + PartialEq,
-    I::Item: PartialEq + Copy,
+    FB: PeekBuffer<I::Item> + PartialEq,
+    BB: PeekBuffer<I::Item> + PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         self.iter == other.iter && self.front == other.front && self.back == other.back
     }
 }
 
+impl<I, const F: usize, const B: usize, FB, BB> core::hash::Hash for SizedPeekDN<I, F, B, FB, BB>
+where
+    I: DoubleEndedIterator + core::hash::Hash,
+    FB: PeekBuffer<I::Item> + core::hash::Hash,
+    BB: PeekBuffer<I::Item> + core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.front.hash(state);
+        self.back.hash(state);
+    }
+}
+
 impl<I, const B: usize, const F: usize> SizedPeekDN<I, B, F>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     pub fn new(iter: I) -> Self {
+        SizedPeekDN::with_buffers(iter, Buffer::new(), Buffer::new())
+    }
+}
+
+impl<I, const B: usize, const F: usize, FB, BB> SizedPeekDN<I, B, F, FB, BB>
+where
+    I: DoubleEndedIterator,
+    FB: PeekBuffer<I::Item>,
+    BB: PeekBuffer<I::Item>,
+{
+    /// Creates a new `SizedPeekDN` that stores its front and back lookahead in the given
+    /// buffers.
+    ///
+    /// Items already in `front` are yielded first by `next()`; items already in `back` are
+    /// yielded first by `next_back()`.
+    pub fn with_buffers(iter: I, front: FB, back: BB) -> Self {
         SizedPeekDN {
             iter,
-            front: Buffer::new(),
-            back: Buffer::new(),
+            front,
+            back,
+            front_spilled: false,
         }
     }
 
@@ -202,6 +338,10 @@ where
     /// The element at index `n` will be returned if available and within the
     /// configured peek buffer size.
     ///
+    /// If the iterator ends before index `n`, the element is read from the back buffer
+    /// instead; [`front_peek_spilled_to_back`](Self::front_peek_spilled_to_back) reports
+    /// when that happened.
+    ///
     /// # Arguments
     /// * `n` - Zero-based index from the front of the iterator.
     ///
@@ -211,10 +351,11 @@ where
     /// # Returns
     /// `Some(&item)` if the element exists within bounds, otherwise `None`.
     pub fn peek_front_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_front_nth() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
+
+        self.front_spilled = false;
 
         if self.front.len() > n {
             return self.front.get(n);
@@ -224,7 +365,11 @@ where
             match self.iter.next() {
                 Some(item) => self.front.push_back(item),
                 None => {
-                    return self.back.get((self.back.len() + self.front.len()).checked_sub(n + 1)?);
+                    let item = self
+                        .back
+                        .get((self.back.len() + self.front.len()).checked_sub(n + 1)?);
+                    self.front_spilled = item.is_some();
+                    return item;
                 }
             }
         }
@@ -246,10 +391,9 @@ where
     /// # Returns
     /// `Some(&item)` if the element exists within bounds, otherwise `None`.
     pub fn peek_back_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_back_nth() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.back.len() > n {
             return self.back.get(n);
@@ -269,7 +413,9 @@ where
 
     /// Mutably peeks at the `n`-th front element without consuming it.
     ///
-    /// Internally fills the front buffer up to index `n` if necessary.
+    /// Internally fills the front buffer up to index `n` if necessary. As with
+    /// `peek_front_nth`, the returned reference may point into the back buffer
+    /// once the iterator is exhausted.
     ///
     /// # Panics
     /// Panics if `n >= self.front_capacity()`.
@@ -277,10 +423,11 @@ where
     /// # Returns
     /// `Some(&mut item)` if available, otherwise `None`.
     pub fn peek_front_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_front_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
+
+        self.front_spilled = false;
 
         if self.front.len() > n {
             return self.front.get_mut(n);
@@ -290,9 +437,11 @@ where
             match self.iter.next() {
                 Some(item) => self.front.push_back(item),
                 None => {
-                    return self
+                    let item = self
                         .back
                         .get_mut((self.back.len() + self.front.len()).checked_sub(n + 1)?);
+                    self.front_spilled = item.is_some();
+                    return item;
                 }
             }
         }
@@ -310,10 +459,9 @@ where
     /// # Returns
     /// `Some(&mut item)` if available, otherwise `None`.
     pub fn peek_back_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_back_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.back.len() > n {
             return self.back.get_mut(n);
@@ -369,7 +517,7 @@ where
     pub fn peek_front_range<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &I::Item>
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + Clone
     where
         I: ExactSizeIterator,
     {
@@ -377,11 +525,6 @@ where
 
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
-
         if start >= end {
             return Either::Single(self.front.range(0..0));
         }
@@ -424,7 +567,7 @@ where
     pub fn peek_back_range<R: RangeBounds<usize>>(
         &mut self,
         range: R,
-    ) -> impl DoubleEndedIterator<Item = &I::Item>
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + Clone
     where
         I: ExactSizeIterator,
     {
@@ -432,11 +575,6 @@ where
 
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
-
         if start >= end {
             return Either::Single(self.back.range(0..0));
         }
@@ -487,11 +625,6 @@ where
 
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
-
         if start >= end {
             return Either::Single(self.front.range_mut(0..0));
         }
@@ -542,11 +675,6 @@ where
 
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
-
         if start >= end {
             return Either::Single(self.back.range_mut(0..0));
         }
@@ -625,6 +753,22 @@ where
         self.next_back_if(|next| next == expected)
     }
 
+    /// Pushes `item` back to the front, so the next `next()` or `peek_front()` returns it.
+    ///
+    /// # Panics
+    /// Panics if the front buffer is full.
+    pub fn put_back_front(&mut self, item: I::Item) {
+        self.front.push_front(item);
+    }
+
+    /// Pushes `item` back to the back, so the next `next_back()` or `peek_back()` returns it.
+    ///
+    /// # Panics
+    /// Panics if the back buffer is full.
+    pub fn put_back_back(&mut self, item: I::Item) {
+        self.back.push_front(item);
+    }
+
     /// Converts `PeekDN` into a standard `Peekable`, discarding peeked items.
     ///
     /// This is a lossy conversion.
@@ -685,7 +829,7 @@ where
             until,
             self.front.len()
         );
-        self.front.drain(..until);
+        self.front.drain_front(until);
     }
 
     /// Removes up to `until` items from the back peek buffer.
@@ -698,7 +842,7 @@ where
             self.back.len()
         );
         let until = until.min(self.back.len());
-        self.back.drain(..until);
+        self.back.drain_front(until);
     }
 
     /// Drains both front and back peek buffers up to the given limits.
@@ -817,6 +961,64 @@ where
         count
     }
 
+    /// Peeks forward without consuming while the predicate returns `true`, up to `max` items.
+    ///
+    /// Like [`while_peek_front`](Self::while_peek_front), but stops after
+    /// `min(max, front_capacity())` items.
+    ///
+    /// # Arguments
+    /// * `max` - Maximum number of items to count.
+    /// * `func` - A predicate to test each peeked item.
+    ///
+    /// # Returns
+    /// The number of front items that match the predicate.
+    pub fn while_peek_front_max(
+        &mut self,
+        max: usize,
+        mut func: impl FnMut(&I::Item) -> bool,
+    ) -> usize {
+        let mut count = 0;
+        let limit = max.min(self.front_capacity());
+
+        while count < limit {
+            match self.peek_front_nth(count) {
+                Some(item) if func(item) => count += 1,
+                _ => break,
+            }
+        }
+
+        count
+    }
+
+    /// Peeks backward without consuming while the predicate returns `true`, up to `max` items.
+    ///
+    /// Like [`while_peek_back`](Self::while_peek_back), but stops after
+    /// `min(max, back_capacity())` items.
+    ///
+    /// # Arguments
+    /// * `max` - Maximum number of items to count.
+    /// * `func` - A predicate to test each peeked item.
+    ///
+    /// # Returns
+    /// The number of back items that match the predicate.
+    pub fn while_peek_back_max(
+        &mut self,
+        max: usize,
+        mut func: impl FnMut(&I::Item) -> bool,
+    ) -> usize {
+        let mut count = 0;
+        let limit = max.min(self.back_capacity());
+
+        while count < limit {
+            match self.peek_back_nth(count) {
+                Some(item) if func(item) => count += 1,
+                _ => break,
+            }
+        }
+
+        count
+    }
+
     /// Returns the maximum number of elements that can be peeked from the front.
     ///
     /// This value represents the logical upper limit of peekable elements from the front,
@@ -843,6 +1045,63 @@ where
         self.back.capacity()
     }
 
+    /// Returns `true` if the last `peek_front_nth` or `peek_front_nth_mut` call was served
+    /// from the back buffer.
+    ///
+    /// This happens when the iterator is exhausted before the requested index, so the item
+    /// had already been back-peeked. Such an item does not count against `front_capacity()`,
+    /// and edits through `peek_front_nth_mut` are made to the back buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::sizedpeekdn;
+    ///
+    /// let mut iter = sizedpeekdn::<_, 3, 3>(0..3);
+    /// iter.peek_back_nth(1);
+    ///
+    /// assert_eq!(iter.peek_front_nth(2), Some(&2));
+    /// assert!(iter.front_peek_spilled_to_back());
+    /// assert_eq!(iter.front_peeked_len(), 1);
+    ///
+    /// assert_eq!(iter.peek_front_nth(0), Some(&0));
+    /// assert!(!iter.front_peek_spilled_to_back());
+    /// ```
+    pub fn front_peek_spilled_to_back(&self) -> bool {
+        self.front_spilled
+    }
+
+    /// Returns how many more elements can be peeked from the front before its buffer is full.
+    ///
+    /// Equivalent to `front_capacity() - front_peeked_len()`.
+    #[inline]
+    pub fn front_remaining_capacity(&self) -> usize {
+        self.front_capacity() - self.front_peeked_len()
+    }
+
+    /// Returns how many more elements can be peeked from the back before its buffer is full.
+    ///
+    /// Equivalent to `back_capacity() - back_peeked_len()`.
+    #[inline]
+    pub fn back_remaining_capacity(&self) -> usize {
+        self.back_capacity() - self.back_peeked_len()
+    }
+
+    /// Returns `true` if the front buffer holds `front_capacity()` elements.
+    ///
+    /// Pushing another element to the front (e.g. a failed `next_if`) would panic.
+    #[inline]
+    pub fn front_is_full(&self) -> bool {
+        self.front_remaining_capacity() == 0
+    }
+
+    /// Returns `true` if the back buffer holds `back_capacity()` elements.
+    ///
+    /// Pushing another element to the back (e.g. a failed `next_back_if`) would panic.
+    #[inline]
+    pub fn back_is_full(&self) -> bool {
+        self.back_remaining_capacity() == 0
+    }
+
     fn next_with_source(&mut self) -> Option<PeekSource<I::Item>> {
         if let Some(front) = self.front.pop_front() {
             Some(PeekSource::Front(front))
@@ -855,9 +1114,9 @@ where
 
     fn cache_front(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front.push_back(front),
+            PeekSource::Front(front) => self.front.push_front(front),
             PeekSource::Iter(iter) => self.front.push_back(iter),
-            PeekSource::Back(back) => self.back.push_front(back),
+            PeekSource::Back(back) => self.back.push_back(back),
         }
     }
 
@@ -873,16 +1132,15 @@ where
 
     fn cache_back(&mut self, item: PeekSource<I::Item>) {
         match item {
-            PeekSource::Front(front) => self.front.push_front(front),
+            PeekSource::Front(front) => self.front.push_back(front),
             PeekSource::Iter(iter) => self.back.push_back(iter),
-            PeekSource::Back(back) => self.back.push_back(back),
+            PeekSource::Back(back) => self.back.push_front(back),
         }
     }
 }
 impl<I, const B: usize, const F: usize> SizedPeekDN<Peekable<I>, B, F>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     /// Constructs `SizedPeekDN` from a `Peekable` iterator, discarding peeked state.
     pub fn from_peekable_lossy(peekable: Peekable<I>) -> Self {
@@ -906,7 +1164,6 @@ where
 pub fn sizedpeekdn<I, const F: usize, const B: usize>(iter: I) -> SizedPeekDN<I, F, B>
 where
     I: DoubleEndedIterator,
-    I::Item: Copy,
 {
     SizedPeekDN::new(iter)
 }
@@ -0,0 +1,53 @@
+use crate::PeekN;
+
+impl<I: Iterator<Item = u8>> PeekN<I> {
+    /// Peeks the next two bytes as a big-endian `u16` without consuming them.
+    ///
+    /// Returns `None` if fewer than two bytes remain.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(vec![0x12, 0x34, 0x56].into_iter());
+    /// assert_eq!(iter.peek_u16_be(), Some(0x1234));
+    /// assert_eq!(iter.next(), Some(0x12));
+    /// ```
+    pub fn peek_u16_be(&mut self) -> Option<u16> {
+        self.peek_bytes().map(u16::from_be_bytes)
+    }
+
+    /// Consumes the next two bytes as a big-endian `u16`.
+    ///
+    /// Nothing is consumed if fewer than two bytes remain.
+    pub fn next_u16_be(&mut self) -> Option<u16> {
+        let value = self.peek_u16_be()?;
+        self.buffer.drain(..2);
+        Some(value)
+    }
+
+    /// Peeks the next four bytes as a big-endian `u32` without consuming them.
+    ///
+    /// Returns `None` if fewer than four bytes remain.
+    pub fn peek_u32_be(&mut self) -> Option<u32> {
+        self.peek_bytes().map(u32::from_be_bytes)
+    }
+
+    /// Consumes the next four bytes as a big-endian `u32`.
+    ///
+    /// Nothing is consumed if fewer than four bytes remain.
+    pub fn next_u32_be(&mut self) -> Option<u32> {
+        let value = self.peek_u32_be()?;
+        self.buffer.drain(..4);
+        Some(value)
+    }
+
+    fn peek_bytes<const K: usize>(&mut self) -> Option<[u8; K]> {
+        self.peek_nth(K.checked_sub(1)?)?;
+
+        let mut bytes = [0; K];
+        for (dst, src) in bytes.iter_mut().zip(&self.buffer) {
+            *dst = *src;
+        }
+        Some(bytes)
+    }
+}
@@ -0,0 +1,83 @@
+use core::iter::FusedIterator;
+
+use crate::PeekN;
+
+/// A [`PeekN`] that folds every consumed item into a running checksum.
+///
+/// Created by [`PeekN::checksummed`]. The update function runs once per item returned by
+/// `next()`, in order. Peeking does not touch the checksum, so lookahead can be freely
+/// mixed with consumption.
+pub struct Checksummed<I: Iterator, F, S> {
+    pub(crate) peekn: PeekN<I>,
+    pub(crate) update: F,
+    pub(crate) state: S,
+}
+
+impl<I, F, S> Checksummed<I, F, S>
+where
+    I: Iterator,
+    F: FnMut(&mut S, &I::Item),
+{
+    /// Returns the checksum of all items consumed so far.
+    #[inline]
+    pub fn checksum(&self) -> &S {
+        &self.state
+    }
+
+    /// Peeks at the next item without consuming it or updating the checksum.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peekn.peek()
+    }
+
+    /// Peeks at the `n`-th upcoming item without consuming it or updating the checksum.
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.peekn.peek_nth(n)
+    }
+
+    /// Returns the inner `PeekN` and the checksum of the items consumed so far.
+    pub fn into_parts(self) -> (PeekN<I>, S) {
+        (self.peekn, self.state)
+    }
+}
+
+impl<I, F, S> Iterator for Checksummed<I, F, S>
+where
+    I: Iterator,
+    F: FnMut(&mut S, &I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.peekn.next()?;
+        (self.update)(&mut self.state, &item);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.peekn.size_hint()
+    }
+}
+
+impl<I, F, S> FusedIterator for Checksummed<I, F, S>
+where
+    I: FusedIterator,
+    F: FnMut(&mut S, &I::Item),
+{
+}
+
+impl<I, F, S> core::fmt::Debug for Checksummed<I, F, S>
+where
+    I: Iterator + core::fmt::Debug,
+    I::Item: core::fmt::Debug,
+    S: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Checksummed")
+            .field("peekn", &self.peekn)
+            .field("checksum", &self.state)
+            .finish()
+    }
+}
@@ -1,14 +1,17 @@
+use crate::{
+    Checksummed, Lookahead, PeekEntry, PeekMut, PeekRange, PeekRangeMut, ScanPeeked, SizedPeekN,
+    VacantEntry,
+};
 #[cfg(feature = "peekde")]
-use crate::PeekableDE;
-use crate::SizedPeekN;
+use crate::{IntoSizedError, PeekError, PeekableDE};
 use core::{
     iter::{FusedIterator, Iterator, Peekable},
-    ops::RangeBounds,
+    ops::{Add, Bound, ControlFlow, RangeBounds},
 };
 
 extern crate alloc;
 
-use alloc::collections::VecDeque;
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 
 /// `PeekN` is an iterator adapter that allows peeking at any future element
 /// in the iterator, not just the next one.
@@ -28,21 +31,89 @@ use alloc::collections::VecDeque;
 /// assert_eq!(iter.peek_nth(2), Some(&3));
 /// assert_eq!(iter.next(), Some(1));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "I: serde::Serialize, I::Item: serde::Serialize",
+        deserialize = "I: serde::Deserialize<'de>, I::Item: serde::Deserialize<'de>"
+    ))
+)]
 pub struct PeekN<I: Iterator> {
     pub(crate) iter: I,
     pub(crate) buffer: VecDeque<I::Item>,
+    /// The final item, taken from the back by `peek_last`. It follows everything in `iter`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) tail: Option<I::Item>,
+    #[cfg(debug_assertions)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) rebuffer: RebufferTracker,
+}
+
+/// Debug-build bookkeeping for [`PeekN::debug_rebuffer_count`].
+#[cfg(debug_assertions)]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct RebufferTracker {
+    /// Buffer length discarded by the last `clear_peeked`, until the next peek or `next`.
+    cleared: usize,
+    count: usize,
 }
 
 impl<I: Iterator> Iterator for PeekN<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(debug_assertions)]
+        {
+            self.rebuffer.cleared = 0;
+        }
+
         if let Some(item) = self.buffer.pop_front() {
             Some(item)
         } else {
-            self.iter.next()
+            self.pull()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.held_len())
+    }
+
+    fn count(self) -> usize {
+        self.held_len() + self.iter.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        let mut buffer = self.buffer;
+        self.tail
+            .or_else(|| self.iter.last())
+            .or_else(|| buffer.pop_back())
+    }
+}
+
+/// Yields items from the back of the stream.
+///
+/// `next_back` pulls from the underlying iterator first, so already-peeked front
+/// items are only taken from the back once the underlying iterator is exhausted.
+/// Peeked items are still returned by `next()` in their original order.
+///
+/// # Example
+/// ```
+/// # use peeknth::peekn;
+/// let mut iter = peekn(0..4);
+/// assert_eq!(iter.peek_nth(1), Some(&1));
+/// assert_eq!(iter.next_back(), Some(3));
+/// assert_eq!(iter.next_back(), Some(2));
+/// assert_eq!(iter.next_back(), Some(1));
+/// assert_eq!(iter.next(), Some(0));
+/// ```
+impl<I: DoubleEndedIterator> DoubleEndedIterator for PeekN<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail
+            .take()
+            .or_else(|| self.iter.next_back())
+            .or_else(|| self.buffer.pop_back())
+    }
 }
 
 impl<I> From<Peekable<I>> for PeekN<Peekable<I>>
@@ -56,6 +127,9 @@ where
         PeekN {
             iter: peekable,
             buffer,
+            tail: None,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
         }
     }
 }
@@ -63,24 +137,58 @@ where
 impl<I, const S: usize> From<SizedPeekN<I, S>> for PeekN<I>
 where
     I: Iterator,
-    I::Item: Copy,
 {
     fn from(value: SizedPeekN<I, S>) -> Self {
         PeekN {
             iter: value.iter,
             buffer: VecDeque::from(value.buffer),
+            tail: None,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
         }
     }
 }
 
 #[cfg(feature = "peekde")]
-impl<I: DoubleEndedIterator> From<PeekableDE<I>> for PeekN<I> {
-    fn from(peekable_de: PeekableDE<I>) -> Self {
-        let buffer = peekable_de.front.flatten().into_iter().collect();
-        PeekN {
+impl<I: DoubleEndedIterator> TryFrom<PeekableDE<I>> for PeekN<I> {
+    type Error = IntoSizedError<PeekableDE<I>>;
+
+    /// Moves the peeked slots of a `PeekableDE` into a `PeekN`.
+    ///
+    /// Front items go to the peek buffer. A single back item becomes the item reserved
+    /// by [`peek_last`](PeekN::peek_last), and once the inner iterator has run dry both
+    /// back items are appended to the peek buffer instead. Otherwise two back items have
+    /// no place in a `PeekN`, so this fails with [`PeekError::BufferOverflow`] and hands
+    /// back the original `PeekableDE` unchanged.
+    fn try_from(peekable_de: PeekableDE<I>) -> Result<Self, Self::Error> {
+        let exhausted = matches!(peekable_de.front, Some(None));
+        if peekable_de.back_second.is_some() && !exhausted {
+            return Err(IntoSizedError {
+                source: peekable_de,
+                error: PeekError::BufferOverflow { capacity: 1 },
+            });
+        }
+
+        let mut buffer: VecDeque<_> = peekable_de
+            .front
+            .flatten()
+            .into_iter()
+            .chain(peekable_de.front_second)
+            .collect();
+        let back = peekable_de.back.flatten();
+        let tail = if exhausted {
+            buffer.extend(peekable_de.back_second.into_iter().chain(back));
+            None
+        } else {
+            back
+        };
+        Ok(PeekN {
             iter: peekable_de.iter,
             buffer,
-        }
+            tail,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
+        })
     }
 }
 
@@ -93,12 +201,26 @@ where
         PeekN {
             iter: self.iter.clone(),
             buffer: self.buffer.clone(),
+            tail: self.tail.clone(),
+            #[cfg(debug_assertions)]
+            rebuffer: self.rebuffer,
         }
     }
 }
 
 impl<I: FusedIterator> FusedIterator for PeekN<I> {}
 
+#[cfg(feature = "itertools")]
+impl<I: Iterator> itertools::PeekingNext for PeekN<I> {
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(accept)
+    }
+}
+
 impl<I> core::fmt::Debug for PeekN<I>
 where
     I: Iterator + core::fmt::Debug,
@@ -108,6 +230,7 @@ where
         f.debug_struct("PeekN")
             .field("iter", &self.iter)
             .field("buffer", &self.buffer)
+            .field("tail", &self.tail)
             .finish()
     }
 }
@@ -125,13 +248,44 @@ where
     I::Item: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.iter == other.iter && self.buffer == other.buffer
+        self.iter == other.iter && self.buffer == other.buffer && self.tail == other.tail
+    }
+}
+
+impl<I> core::hash::Hash for PeekN<I>
+where
+    I: Iterator + core::hash::Hash,
+    I::Item: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.buffer.hash(state);
+        self.tail.hash(state);
     }
 }
 
 impl<I: ExactSizeIterator> ExactSizeIterator for PeekN<I> {
     fn len(&self) -> usize {
-        self.buffer.len() + self.iter.len()
+        self.held_len() + self.iter.len()
+    }
+}
+
+/// Inserts the supplied items ahead of the underlying iterator, in the order given.
+///
+/// The items are appended to the peek buffer: anything already peeked is still yielded
+/// first, then the supplied items, and only then does the underlying iterator resume.
+///
+/// # Example
+/// ```
+/// # use peeknth::peekn;
+/// let mut iter = peekn(10..12);
+/// assert_eq!(iter.peek(), Some(&10));
+/// iter.extend([1, 2]);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![10, 1, 2, 11]);
+/// ```
+impl<I: Iterator> Extend<I::Item> for PeekN<I> {
+    fn extend<T: IntoIterator<Item = I::Item>>(&mut self, items: T) {
+        self.buffer.extend(items);
     }
 }
 
@@ -141,6 +295,9 @@ impl<I: Iterator> PeekN<I> {
         PeekN {
             iter,
             buffer: VecDeque::new(),
+            tail: None,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
         }
     }
 
@@ -162,6 +319,32 @@ impl<I: Iterator> PeekN<I> {
         PeekN {
             iter,
             buffer: VecDeque::with_capacity(capacity),
+            tail: None,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
+        }
+    }
+
+    /// Creates a `PeekN` from an iterator, a preloaded peek buffer and an optional tail item.
+    ///
+    /// The buffer contents are assumed to logically precede the iterator: they are
+    /// yielded first, in order, before anything is pulled from `iter`. The tail item, if
+    /// any, follows the iterator, as one reserved by [`peek_last`](PeekN::peek_last) does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::PeekN;
+    /// let mut iter = PeekN::from_parts(3..5, [1, 2].into(), Some(5));
+    /// assert_eq!(iter.peeked_len(), 2);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn from_parts(iter: I, buffer: VecDeque<I::Item>, tail: Option<I::Item>) -> Self {
+        PeekN {
+            iter,
+            buffer,
+            tail,
+            #[cfg(debug_assertions)]
+            rebuffer: RebufferTracker::default(),
         }
     }
 
@@ -173,8 +356,7 @@ impl<I: Iterator> PeekN<I> {
     ///
     /// - `n`: The number of steps to look ahead (0-based).
     ///
-    /// # Panics
-    /// This function will panic in debug builds if called with `usize::MAX`.
+    /// Returns `None` for any `n` greater than [`MAX_PEEK_INDEX`](crate::MAX_PEEK_INDEX).
     ///
     /// # Examples
     /// ```
@@ -183,14 +365,18 @@ impl<I: Iterator> PeekN<I> {
     /// assert_eq!(iter.peek_nth(3), Some(&13));
     /// ```
     pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(n < usize::MAX, "peek_nth() with usize::MAX is likely a bug");
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
+        #[cfg(debug_assertions)]
+        self.track_repeek(n);
 
         if self.buffer.len() > n {
             return self.buffer.get(n);
         }
 
         while self.buffer.len() <= n {
-            let next_item = self.iter.next()?;
+            let next_item = self.pull()?;
             self.buffer.push_back(next_item);
         }
 
@@ -205,8 +391,7 @@ impl<I: Iterator> PeekN<I> {
     ///
     /// - `n`: The number of steps to look ahead (0-based).
     ///
-    /// # Panics
-    /// This function will panic in debug builds if called with `usize::MAX`.
+    /// Returns `None` for any `n` greater than [`MAX_PEEK_INDEX`](crate::MAX_PEEK_INDEX).
     ///
     /// # Examples
     /// ```
@@ -219,23 +404,318 @@ impl<I: Iterator> PeekN<I> {
     /// assert_eq!(iter.next(), Some(11));
     /// ```
     pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
+        #[cfg(debug_assertions)]
+        self.track_repeek(n);
 
         if self.buffer.len() > n {
             return self.buffer.get_mut(n);
         }
 
         while self.buffer.len() <= n {
-            let next_item = self.iter.next()?;
+            let next_item = self.pull()?;
             self.buffer.push_back(next_item);
         }
 
         self.buffer.get_mut(n)
     }
 
+    /// Returns a mutable reference to the `n`-th element, synthesizing it if the iterator ends
+    /// exactly at index `n`.
+    ///
+    /// If the `n`-th element can be peeked, it is returned as with `peek_nth_mut`. If the
+    /// iterator is exhausted and `n` is the next free slot (`n == peeked_len()`), the value
+    /// produced by `f` is appended to the buffer and returned. `f` is not called otherwise.
+    ///
+    /// # Panics
+    /// Panics if the iterator ends before index `n` and `n > peeked_len()`, since that would
+    /// leave a gap in the buffer.
+    pub fn peek_nth_or_insert_with(
+        &mut self,
+        n: usize,
+        f: impl FnOnce() -> I::Item,
+    ) -> &mut I::Item {
+        if self.peek_nth(n).is_none() {
+            assert!(
+                n == self.buffer.len(),
+                "peek_nth_or_insert_with: index {} is past the next free slot {}",
+                n,
+                self.buffer.len()
+            );
+            self.buffer.push_back(f());
+        }
+
+        &mut self.buffer[n]
+    }
+
+    /// Peeks at the `n`-th item and maps it through `f`, without consuming anything.
+    ///
+    /// # Returns
+    /// `None` if the iterator ends before index `n` or if `f` returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(["let", "x", "="].into_iter());
+    /// let ident = iter.peek_nth_if(1, |s| s.chars().all(char::is_alphabetic).then_some(s.len()));
+    /// assert_eq!(ident, Some(1));
+    /// assert_eq!(iter.next(), Some("let"));
+    /// ```
+    pub fn peek_nth_if<R>(&mut self, n: usize, f: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        self.peek_nth(n).and_then(f)
+    }
+
+    /// Maps the next `n` items through `f` and collects the results, without consuming them.
+    ///
+    /// The buffer is filled up to `n` items first. If the iterator ends earlier,
+    /// only the available items are mapped.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..);
+    /// let squares: Vec<_> = iter.peek_map(3, |x| x * x);
+    /// assert_eq!(squares, vec![1, 4, 9]);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn peek_map<R, B: FromIterator<R>>(&mut self, n: usize, f: impl FnMut(&I::Item) -> R) -> B {
+        if let Some(last) = n.checked_sub(1) {
+            let _ = self.peek_nth(last);
+        }
+
+        self.buffer.iter().take(n).map(f).collect()
+    }
+
+    /// Returns the smallest of the next `n` items, without consuming them.
+    ///
+    /// # Returns
+    /// An owned copy of the minimum among the available items, or `None` if there are none.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([4, 1, 3, 0].into_iter());
+    /// assert_eq!(iter.peek_min_copied(3), Some(1));
+    /// assert_eq!(iter.next(), Some(4));
+    /// ```
+    pub fn peek_min_copied(&mut self, n: usize) -> Option<I::Item>
+    where
+        I::Item: Copy + Ord,
+    {
+        self.lookahead(n).iter().copied().min()
+    }
+
+    /// Returns the largest of the next `n` items, without consuming them.
+    ///
+    /// # Returns
+    /// An owned copy of the maximum among the available items, or `None` if there are none.
+    pub fn peek_max_copied(&mut self, n: usize) -> Option<I::Item>
+    where
+        I::Item: Copy + Ord,
+    {
+        self.lookahead(n).iter().copied().max()
+    }
+
+    /// Fills the buffer with up to `n` items and returns a read-only window over them.
+    ///
+    /// # Returns
+    /// A [`Lookahead`] borrowing the buffer. Its length is `n`, or less if the iterator
+    /// ended early.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("abc".chars());
+    /// let window = iter.lookahead(4);
+    /// assert_eq!(window.len(), 3);
+    /// assert_eq!(window.get(1), Some(&'b'));
+    /// assert_eq!(window.get(3), None);
+    /// ```
+    pub fn lookahead(&mut self, n: usize) -> Lookahead<'_, I> {
+        if let Some(last) = n.checked_sub(1) {
+            let _ = self.peek_nth(last);
+        }
+
+        Lookahead {
+            len: n.min(self.buffer.len()),
+            buffer: &self.buffer,
+        }
+    }
+
+    /// Runs a variable-length lookahead decision in one scoped call.
+    ///
+    /// `f` is called with a window over the next items, starting empty. It returns
+    /// `ControlFlow::Break(result)` once it has decided, or `ControlFlow::Continue(extra)` to
+    /// have `extra` more items buffered and be called again with the larger window.
+    ///
+    /// # Returns
+    /// `Some(result)` from the first `Break`, or `None` if the window cannot grow any further
+    /// (the iterator is exhausted, or `extra` is `0`).
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// use core::ops::ControlFlow;
+    ///
+    /// let mut iter = peekn("ab;c".chars());
+    /// let semi = iter.peek_adaptive(|window| match window.iter().position(|&c| c == ';') {
+    ///     Some(i) => ControlFlow::Break(i),
+    ///     None => ControlFlow::Continue(1),
+    /// });
+    /// assert_eq!(semi, Some(2));
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    pub fn peek_adaptive<R>(
+        &mut self,
+        mut f: impl FnMut(&mut Lookahead<'_, I>) -> ControlFlow<R, usize>,
+    ) -> Option<R> {
+        let mut len = 0;
+        loop {
+            let mut window = Lookahead {
+                len: len.min(self.buffer.len()),
+                buffer: &self.buffer,
+            };
+            let shown = window.len();
+
+            let extra = match f(&mut window) {
+                ControlFlow::Break(result) => return Some(result),
+                ControlFlow::Continue(extra) => extra,
+            };
+            if extra == 0 {
+                return None;
+            }
+
+            len = shown.saturating_add(extra);
+            let _ = self.peek_nth(len - 1);
+            if self.buffer.len() <= shown {
+                return None;
+            }
+        }
+    }
+
+    /// Peeks at the next `K` items as an array of references.
+    ///
+    /// Handy for pattern-matching on fixed-width lookahead.
+    ///
+    /// # Returns
+    /// `Some([&item0, ..., &itemK-1])`, or `None` if fewer than `K` items remain.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("=>x".chars());
+    /// assert!(matches!(iter.peek_chunk::<2>(), Some(['=', '>'])));
+    /// assert_eq!(iter.peek_chunk::<4>(), None);
+    /// ```
+    pub fn peek_chunk<const K: usize>(&mut self) -> Option<[&I::Item; K]> {
+        if let Some(last) = K.checked_sub(1) {
+            self.peek_nth(last)?;
+        }
+
+        let (head, tail) = self.buffer.as_slices();
+        Some(core::array::from_fn(|i| match head.get(i) {
+            Some(item) => item,
+            None => &tail[i - head.len()],
+        }))
+    }
+
+    /// Yields up to `count` overlapping windows of `K` upcoming items, without consuming them.
+    ///
+    /// Up to `count + K - 1` items are buffered first. Nothing is consumed, so a later
+    /// `next()` still returns the first item of the first window.
+    ///
+    /// # Arguments
+    /// - `count`: the maximum number of windows to yield.
+    ///
+    /// # Returns
+    /// An iterator over `[&item; K]` windows, each starting one item after the previous.
+    /// It stops early if the iterator ends before `count` windows are available.
+    ///
+    /// # Panics
+    /// Panics if `K` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("a<=b".chars());
+    /// let digraph = iter.peek_windows::<2>(3).position(|w| matches!(w, ['<', '=']));
+    /// assert_eq!(digraph, Some(1));
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    pub fn peek_windows<const K: usize>(
+        &mut self,
+        count: usize,
+    ) -> impl Iterator<Item = [&I::Item; K]> + '_ {
+        assert!(K != 0, "peek_windows: window size must be non-zero");
+
+        if count != 0 {
+            let _ = self.peek_nth(count.saturating_add(K - 1) - 1);
+        }
+
+        let windows = (self.buffer.len() + 1).saturating_sub(K).min(count);
+        let (head, tail) = self.buffer.as_slices();
+        (0..windows).map(move |start| {
+            core::array::from_fn(|i| match head.get(start + i) {
+                Some(item) => item,
+                None => &tail[start + i - head.len()],
+            })
+        })
+    }
+
+    /// Consumes and returns the next `K` items as an array.
+    ///
+    /// # Returns
+    /// `Some([item0, ..., itemK-1])`, or `None` if fewer than `K` items remain. In that case
+    /// nothing is consumed; the remaining items stay in the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..5);
+    /// assert_eq!(iter.next_chunk::<3>(), Some([0, 1, 2]));
+    /// assert_eq!(iter.next_chunk::<3>(), None);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn next_chunk<const K: usize>(&mut self) -> Option<[I::Item; K]> {
+        if let Some(last) = K.checked_sub(1) {
+            self.peek_nth(last)?;
+        }
+
+        Some(core::array::from_fn(|_| {
+            self.buffer
+                .pop_front()
+                .expect("next_chunk: buffer was filled above")
+        }))
+    }
+
+    /// Returns an entry for the lookahead slot at index `n`.
+    ///
+    /// The buffer is filled up to index `n`. If the iterator ends before that,
+    /// the entry is [`PeekEntry::Vacant`] and a value can be appended with
+    /// [`or_insert`](PeekEntry::or_insert) as long as `n` is the next free slot.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..3);
+    ///
+    /// iter.front_entry(0).and_modify(|x| *x *= 10);
+    /// assert_eq!(*iter.front_entry(2).or_insert(0), 0);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![10, 2, 0]);
+    /// ```
+    pub fn front_entry(&mut self, n: usize) -> PeekEntry<'_, I::Item> {
+        if self.peek_nth(n).is_some() {
+            PeekEntry::Occupied(&mut self.buffer[n])
+        } else {
+            PeekEntry::Vacant(VacantEntry {
+                buffer: &mut self.buffer,
+                index: n,
+            })
+        }
+    }
+
     /// Peeks at the next item in the iterator without consuming it.
     ///
     /// Equivalent to `peek_nth(0)`.
@@ -246,8 +726,17 @@ impl<I: Iterator> PeekN<I> {
     /// let mut iter = peekn(10..);
     /// assert_eq!(iter.peek(), Some(&10));
     /// ```
+    #[inline]
     pub fn peek(&mut self) -> Option<&I::Item> {
-        self.peek_nth(0)
+        #[cfg(debug_assertions)]
+        self.track_repeek(0);
+
+        if self.buffer.is_empty() {
+            let next_item = self.pull()?;
+            self.buffer.push_back(next_item);
+        }
+
+        self.buffer.front()
     }
 
     /// Peeks at the next item in the iterator as a mutable reference, without consuming it.
@@ -265,8 +754,98 @@ impl<I: Iterator> PeekN<I> {
     /// }
     /// assert_eq!(iter.next(), Some(100));
     /// ```
+    #[inline]
     pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
-        self.peek_nth_mut(0)
+        #[cfg(debug_assertions)]
+        self.track_repeek(0);
+
+        if self.buffer.is_empty() {
+            let next_item = self.pull()?;
+            self.buffer.push_back(next_item);
+        }
+
+        self.buffer.front_mut()
+    }
+
+    /// Returns a clone of the `n`-th item, leaving the original buffered.
+    ///
+    /// Intended for shared-ownership items such as `Rc<T>` or `Arc<T>`, where cloning
+    /// only bumps a reference count, so the peeked value can be kept beyond the borrow
+    /// of the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// use std::rc::Rc;
+    ///
+    /// let words: Vec<Rc<str>> = vec!["let".into(), "x".into()];
+    /// let mut iter = peekn(words.into_iter());
+    ///
+    /// let second = iter.peek_nth_shared(1).unwrap();
+    /// assert_eq!(Rc::strong_count(&second), 2);
+    /// assert_eq!(iter.nth(1), Some(second));
+    /// ```
+    pub fn peek_nth_shared(&mut self, n: usize) -> Option<I::Item>
+    where
+        I::Item: Clone,
+    {
+        self.peek_nth(n).cloned()
+    }
+
+    /// Returns a clone of the next item, leaving the original buffered.
+    ///
+    /// Equivalent to `peek_nth_shared(0)`.
+    pub fn peek_shared(&mut self) -> Option<I::Item>
+    where
+        I::Item: Clone,
+    {
+        self.peek().cloned()
+    }
+
+    /// Peeks at the next item and returns a guard that allows mutating it in place.
+    ///
+    /// Unlike `peek_mut`, the returned [`PeekMut`] is a dedicated type, which leaves room to
+    /// run additional logic when the mutation ends.
+    ///
+    /// # Returns
+    /// `None` if the iterator is exhausted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// if let Some(mut x) = iter.peek_mut_guard() {
+    ///     *x += 100;
+    /// }
+    /// assert_eq!(iter.next(), Some(100));
+    /// ```
+    pub fn peek_mut_guard(&mut self) -> Option<PeekMut<'_, I>> {
+        self.peek()?;
+        Some(PeekMut { peekn: self })
+    }
+
+    /// Returns a lazy scan over the upcoming items that buffers them on demand.
+    ///
+    /// Each step of the returned iterator pulls at most one more item, so it is safe to use
+    /// with `take` on infinite iterators. Nothing is consumed: every scanned item stays
+    /// buffered for later `next()` calls.
+    ///
+    /// # Returns
+    /// A [`ScanPeeked`] yielding clones of the upcoming items, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// assert_eq!(iter.scan_peeked().take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// assert_eq!(iter.peeked_len(), 3);
+    /// assert_eq!(iter.next(), Some(0));
+    /// ```
+    pub fn scan_peeked(&mut self) -> ScanPeeked<'_, I> {
+        ScanPeeked {
+            peekn: self,
+            index: 0,
+        }
     }
 
     /// Peeks a range of elements from the internal buffer without consuming them.
@@ -278,10 +857,12 @@ impl<I: Iterator> PeekN<I> {
     ///
     /// # Arguments
     /// * `range` - The range of indices to access within the buffer. Must satisfy `start < end`.
+    ///   An unbounded end refers to the currently buffered items, so `..` never pulls
+    ///   from the iterator.
     ///
     /// # Returns
-    /// A slice of peeked items in the specified range. If the iterator runs out of items,
-    /// the returned slice will be shorter than requested.
+    /// A [`PeekRange`] over the peeked items in the specified range. If the iterator runs out
+    /// of items, it will be shorter than requested.
     ///
     /// # Examples
     /// ```
@@ -290,22 +871,17 @@ impl<I: Iterator> PeekN<I> {
     /// let values: Vec<_> = iter.peek_range(1..4).cloned().collect();
     /// assert_eq!(values, vec![1, 2, 3]);
     /// ```
-    pub fn peek_range<R: RangeBounds<usize>>(
-        &mut self,
-        range: R,
-    ) -> impl Iterator<Item = &<I as Iterator>::Item>
-    where
-        I: ExactSizeIterator,
-    {
+    pub fn peek_range<R: RangeBounds<usize>>(&mut self, range: R) -> PeekRange<'_, I::Item> {
         use crate::get_start_end;
-        let (start, end) = get_start_end(range, self.len());
+        let unbounded_end = matches!(range.end_bound(), core::ops::Bound::Unbounded);
+        let (start, end) = get_start_end(range, self.buffer.len());
 
         core::debug_assert!(
-            start < end,
+            unbounded_end || start < end,
             "peek_range: start ({start}) must be less than end ({end})"
         );
         if start >= end {
-            return self.buffer.range(0..0);
+            return PeekRange::new(self.buffer.as_slices(), 0..0);
         }
 
         for i in start..end {
@@ -321,7 +897,7 @@ impl<I: Iterator> PeekN<I> {
             end,
             self.buffer.len()
         );
-        self.buffer.range(start..safe_end)
+        PeekRange::new(self.buffer.as_slices(), start.min(safe_end)..safe_end)
     }
 
     /// Mutably peeks a range of elements from the internal buffer without consuming them.
@@ -333,26 +909,23 @@ impl<I: Iterator> PeekN<I> {
     ///
     /// # Arguments
     /// * `range` - The range of indices to access within the buffer. Must satisfy `start < end`.
+    ///   An unbounded end refers to the currently buffered items, so `..` never pulls
+    ///   from the iterator.
     ///
     /// # Returns
-    /// A mutable iterator over the available elements in the specified range,
+    /// A [`PeekRangeMut`] over the available elements in the specified range,
     /// potentially shorter than requested if the iterator runs out of items.
-    pub fn peek_range_mut<R: RangeBounds<usize>>(
-        &mut self,
-        range: R,
-    ) -> impl Iterator<Item = &mut <I as Iterator>::Item>
-    where
-        I: ExactSizeIterator,
-    {
+    pub fn peek_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> PeekRangeMut<'_, I::Item> {
         use crate::get_start_end;
-        let (start, end) = get_start_end(range, self.len());
+        let unbounded_end = matches!(range.end_bound(), core::ops::Bound::Unbounded);
+        let (start, end) = get_start_end(range, self.buffer.len());
 
         core::debug_assert!(
-            start < end,
+            unbounded_end || start < end,
             "peek_range: start ({start}) must be less than end ({end})"
         );
         if start >= end {
-            return self.buffer.range_mut(0..0);
+            return PeekRangeMut::new(self.buffer.as_mut_slices(), 0..0);
         }
 
         for i in start..end {
@@ -368,26 +941,156 @@ impl<I: Iterator> PeekN<I> {
             end,
             self.buffer.len()
         );
-        self.buffer.range_mut(start..safe_end)
+        PeekRangeMut::new(self.buffer.as_mut_slices(), start.min(safe_end)..safe_end)
     }
 
-    /// Advances the iterator and returns the next value only if it satisfies the predicate.
+    /// Buffers `range` and overwrites each item in it with a fresh value from `f`.
     ///
-    /// If the next item does not match, it is pushed back to the peek buffer.
+    /// Useful for masking a lookahead window before it is consumed. An unbounded end refers
+    /// to the currently buffered items; if the iterator ends early, only the available items
+    /// are overwritten.
     ///
-    /// # Examples
+    /// # Example
     /// ```
     /// # use peeknth::peekn;
-    /// let mut iter = peekn(0..);
-    /// assert_eq!(iter.next_if(|&x| x < 3), Some(0));
-    /// assert_eq!(iter.next_if(|&x| x > 10), None); // 1 is pushed back
-    /// assert_eq!(iter.peek(), Some(&1));
+    /// let mut iter = peekn("pw=1234;".chars());
+    /// iter.fill_peeked_with(3..7, || '*');
+    /// assert_eq!(iter.collect::<String>(), "pw=****;");
     /// ```
-    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
-        match self.next() {
-            Some(matched) if func(&matched) => Some(matched),
-            Some(other) => {
-                self.buffer.push_front(other);
+    pub fn fill_peeked_with<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        mut f: impl FnMut() -> I::Item,
+    ) {
+        use crate::get_start_end;
+        let (start, end) = get_start_end(range, self.buffer.len());
+
+        if end > 0 {
+            let _ = self.peek_nth(end - 1);
+        }
+
+        let end = end.min(self.buffer.len());
+        for item in PeekRangeMut::new(self.buffer.as_mut_slices(), start.min(end)..end) {
+            *item = f();
+        }
+    }
+
+    /// Peeks a range of elements, reporting whether the whole range was available.
+    ///
+    /// The buffer is filled up to the end of the range in a single pass. An unbounded end
+    /// refers to the currently buffered items.
+    ///
+    /// # Arguments
+    /// * `range` - The range of indices to peek.
+    ///
+    /// # Returns
+    /// `Ok(iter)` if every element of the range could be peeked, or `Err((partial, count))`
+    /// if the iterator ended early, where `partial` yields the `count` elements that were available.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..5);
+    /// assert!(iter.peek_range_checked(1..3).is_ok());
+    ///
+    /// let (partial, count) = iter.peek_range_checked(3..8).err().unwrap();
+    /// assert_eq!(partial.copied().collect::<Vec<_>>(), vec![3, 4]);
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn peek_range_checked<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<impl Iterator<Item = &I::Item>, (impl Iterator<Item = &I::Item>, usize)> {
+        use crate::get_start_end;
+        let (start, end) = get_start_end(range, self.buffer.len());
+
+        if start >= end {
+            return Ok(self.buffer.range(0..0));
+        }
+
+        let _ = self.peek_nth(end - 1);
+
+        let available = end.min(self.buffer.len());
+        if available < end {
+            let start = start.min(available);
+            Err((self.buffer.range(start..available), available - start))
+        } else {
+            Ok(self.buffer.range(start..end))
+        }
+    }
+
+    /// Peeks a range like [`peek_range`](Self::peek_range), pairing each item with its index.
+    ///
+    /// Indices are absolute offsets from the next item, so the first pair has the range's start.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(10..20);
+    /// let found = iter.peek_range_enumerate(2..6).find(|(_, x)| **x % 4 == 0);
+    /// assert_eq!(found, Some((2, &12)));
+    /// ```
+    pub fn peek_range_enumerate<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> impl Iterator<Item = (usize, &I::Item)> {
+        use crate::get_start_end;
+        let (start, _) = get_start_end((range.start_bound().cloned(), Bound::Unbounded), 0);
+
+        self.peek_range(range)
+            .enumerate()
+            .map(move |(i, item)| (start + i, item))
+    }
+
+    /// Peeks a range that starts at `start` and extends while the predicate holds.
+    ///
+    /// This is `while_peek` with an offset start: elements are buffered from index `start`
+    /// until `pred` returns `false` or the iterator is exhausted.
+    ///
+    /// # Returns
+    /// An iterator over the elements in `start..first_failing`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..10);
+    /// let values: Vec<_> = iter.peek_range_while(2, |&x| x < 7).cloned().collect();
+    /// assert_eq!(values, vec![2, 3, 4, 5, 6]);
+    /// ```
+    pub fn peek_range_while(
+        &mut self,
+        start: usize,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl Iterator<Item = &I::Item> {
+        let mut end = start;
+        while let Some(item) = self.peek_nth(end) {
+            if !pred(item) {
+                break;
+            }
+            end += 1;
+        }
+
+        let end = end.min(self.buffer.len());
+        self.buffer.range(start.min(end)..end)
+    }
+
+    /// Advances the iterator and returns the next value only if it satisfies the predicate.
+    ///
+    /// If the next item does not match, it is pushed back to the peek buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// assert_eq!(iter.next_if(|&x| x < 3), Some(0));
+    /// assert_eq!(iter.next_if(|&x| x > 10), None); // 1 is pushed back
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// ```
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next() {
+            Some(matched) if func(&matched) => Some(matched),
+            Some(other) => {
+                self.buffer.push_front(other);
                 None
             }
             None => None,
@@ -414,6 +1117,194 @@ impl<I: Iterator> PeekN<I> {
         self.next_if(|next| next == expected)
     }
 
+    /// Consumes the next item only if `func` maps it to `Some`, and returns the mapped value.
+    ///
+    /// The item is peeked first, so when `func` returns `None` it is left in place and the
+    /// position is unchanged, matching the push-back behavior of [`next_if`](Self::next_if).
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("7x".chars());
+    /// assert_eq!(iter.next_if_map(|c| c.to_digit(10)), Some(7));
+    /// assert_eq!(iter.next_if_map(|c| c.to_digit(10)), None);
+    /// assert_eq!(iter.next(), Some('x'));
+    /// ```
+    pub fn next_if_map<R>(&mut self, func: impl FnOnce(&I::Item) -> Option<R>) -> Option<R> {
+        let mapped = func(self.peek()?)?;
+        self.next();
+        Some(mapped)
+    }
+
+    /// Advances the iterator only if the next value satisfies the predicate and is not the last one.
+    ///
+    /// The final element is never consumed by this method, so the caller can always
+    /// handle it separately (e.g. to omit a trailing separator).
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..3);
+    /// assert_eq!(iter.next_if_not_last(|_| true), Some(0));
+    /// assert_eq!(iter.next_if_not_last(|_| true), Some(1));
+    /// assert_eq!(iter.next_if_not_last(|_| true), None);
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    pub fn next_if_not_last(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item>
+    where
+        I: ExactSizeIterator,
+    {
+        if self.len() > 1 {
+            self.next_if(func)
+        } else {
+            None
+        }
+    }
+
+    /// Consumes the next `preds.len()` items only if each one satisfies the predicate at
+    /// the same position.
+    ///
+    /// The items are peeked first; if any predicate fails or the iterator ends early,
+    /// nothing is consumed.
+    ///
+    /// # Returns
+    /// The consumed items in order, or `None` if the sequence did not match.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let digit = |c: &char| c.is_ascii_digit();
+    /// let dot = |c: &char| *c == '.';
+    /// let mut iter = peekn("1.5x".chars());
+    /// assert_eq!(iter.next_if_seq(&[&dot, &digit]), None);
+    /// assert_eq!(iter.next_if_seq(&[&digit, &dot, &digit]), Some(vec!['1', '.', '5']));
+    /// assert_eq!(iter.next(), Some('x'));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn next_if_seq(&mut self, preds: &[&dyn Fn(&I::Item) -> bool]) -> Option<Vec<I::Item>> {
+        for (i, pred) in preds.iter().enumerate() {
+            if !pred(self.peek_nth(i)?) {
+                return None;
+            }
+        }
+
+        Some(self.buffer.drain(..preds.len()).collect())
+    }
+
+    /// Finds the first upcoming element that satisfies the predicate, without consuming it.
+    ///
+    /// Elements are buffered one by one until `pred` returns `true` or the iterator is exhausted.
+    /// This is the same search as [`peek_find`](Self::peek_find).
+    ///
+    /// # Returns
+    /// The zero-based lookahead index of the match together with a reference to it,
+    /// or `None` if no element matches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([1, 3, 4, 5].into_iter());
+    /// assert_eq!(iter.peek_find_ref(|&x| x % 2 == 0), Some((2, &4)));
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn peek_find_ref(
+        &mut self,
+        pred: impl FnMut(&I::Item) -> bool,
+    ) -> Option<(usize, &I::Item)> {
+        self.peek_find(pred)
+    }
+
+    /// Returns the length of the common prefix shared with another `PeekN`, without consuming either.
+    ///
+    /// Both iterators are peeked in lockstep until an element differs or one of them is exhausted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut a = peekn([1, 2, 3, 9].into_iter());
+    /// let mut b = peekn([1, 2, 3, 8].into_iter());
+    /// assert_eq!(a.common_prefix_len(&mut b), 3);
+    /// assert_eq!(a.next(), Some(1));
+    /// assert_eq!(b.next(), Some(1));
+    /// ```
+    pub fn common_prefix_len<J>(&mut self, other: &mut PeekN<J>) -> usize
+    where
+        J: Iterator,
+        I::Item: PartialEq<J::Item>,
+    {
+        let mut count = 0;
+        while let (Some(a), Some(b)) = (self.peek_nth(count), other.peek_nth(count)) {
+            if a != b {
+                break;
+            }
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Detects a repeating pattern in the upcoming items, without consuming them.
+    ///
+    /// Up to `2 * max_period` items are buffered. A period `p` is reported only if at least
+    /// `2 * p` items are available and every buffered item equals the one `p` positions
+    /// before it.
+    ///
+    /// # Returns
+    /// The smallest such period `p <= max_period`, or `None` if there is none or too few
+    /// items remain to confirm one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("abcabc".chars());
+    /// assert_eq!(iter.peek_cycle_len(3), Some(3));
+    /// assert_eq!(iter.peek_cycle_len(2), None);
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    pub fn peek_cycle_len(&mut self, max_period: usize) -> Option<usize>
+    where
+        I::Item: PartialEq,
+    {
+        let len = self.lookahead(max_period.saturating_mul(2)).len();
+
+        (1..=max_period)
+            .take_while(|p| 2 * p <= len)
+            .find(|&p| (p..len).all(|i| self.buffer[i] == self.buffer[i - p]))
+    }
+
+    /// Consumes items until `f` maps one of them to `Some`, and returns that value.
+    ///
+    /// Every item up to and including the match is consumed; items mapped to `None` are
+    /// discarded. Items after the match are left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// assert_eq!(iter.find_map_front(|&x| (x * x > 10).then_some(x * x)), Some(16));
+    /// assert_eq!(iter.next(), Some(5));
+    /// ```
+    pub fn find_map_front<R>(&mut self, mut f: impl FnMut(&I::Item) -> Option<R>) -> Option<R> {
+        self.by_ref().find_map(|item| f(&item))
+    }
+
+    /// Pushes `item` back to the front, so the next `next()` or `peek()` returns it.
+    ///
+    /// The item need not have come from the iterator. Multiple calls stack, so the
+    /// last item put back is returned first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..3);
+    /// iter.put_back(0);
+    /// assert_eq!(iter.peek(), Some(&0));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn put_back(&mut self, item: I::Item) {
+        self.buffer.push_front(item);
+    }
+
     /// Converts this `PeekN` into a standard `Peekable`, discarding buffered items.
     ///
     /// This is a lossy conversion: any elements stored in the internal buffer will be dropped.
@@ -421,6 +1312,46 @@ impl<I: Iterator> PeekN<I> {
         self.iter.peekable()
     }
 
+    /// Splits this `PeekN` into the inner iterator, the peek buffer and the tail item.
+    ///
+    /// Unlike [`into_peekable_lossy`](Self::into_peekable_lossy), no items are lost: the
+    /// buffered ones precede the iterator, and an item reserved by
+    /// [`peek_last`](PeekN::peek_last) is returned last, as it follows the iterator.
+    /// [`from_parts`](Self::from_parts) reassembles them.
+    pub fn into_parts(self) -> (I, VecDeque<I::Item>, Option<I::Item>) {
+        (self.iter, self.buffer, self.tail)
+    }
+
+    /// Wraps this `PeekN` in an adapter that keeps a running checksum of consumed items.
+    ///
+    /// `update` is called with the state and each item as it is returned by `next()`.
+    /// Items that are only peeked do not affect the checksum.
+    ///
+    /// # Arguments
+    /// - `init`: The initial checksum state.
+    /// - `update`: Folds one consumed item into the state.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([1, 2, 3, 4].into_iter()).checksummed(0, |sum, x| *sum += x);
+    /// assert_eq!(iter.peek_nth(2), Some(&3));
+    /// assert_eq!(*iter.checksum(), 0);
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!(*iter.checksum(), 3);
+    /// ```
+    pub fn checksummed<S, F>(self, init: S, update: F) -> Checksummed<I, F, S>
+    where
+        F: FnMut(&mut S, &I::Item),
+    {
+        Checksummed {
+            peekn: self,
+            update,
+            state: init,
+        }
+    }
+
     /// Returns the number of items currently buffered (peeked but not consumed).
     #[inline]
     pub fn peeked_len(&self) -> usize {
@@ -433,12 +1364,138 @@ impl<I: Iterator> PeekN<I> {
         self.peeked_len() > n
     }
 
+    /// Returns the peeked items as a pair of slices.
+    ///
+    /// The first slice followed by the second holds every peeked element in order, so
+    /// already-buffered items can be scanned without calling `peek_nth` per element.
+    /// The second slice is empty when the buffer is contiguous.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(b"key=value".iter().copied());
+    /// iter.while_peek(|&b| b != b'\n');
+    /// let (head, tail) = iter.as_slices();
+    /// let eq = head.iter().chain(tail).position(|&b| b == b'=');
+    /// assert_eq!(eq, Some(3));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[I::Item], &[I::Item]) {
+        self.buffer.as_slices()
+    }
+
+    /// Returns the peeked items as a pair of mutable slices.
+    ///
+    /// Like [`as_slices`](Self::as_slices), the two slices together hold every peeked
+    /// element in order.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [I::Item], &mut [I::Item]) {
+        self.buffer.as_mut_slices()
+    }
+
+    /// Rearranges the buffer so that all peeked items form a single mutable slice.
+    ///
+    /// See [`VecDeque::make_contiguous`].
+    #[inline]
+    pub fn peeked_make_contiguous(&mut self) -> &mut [I::Item] {
+        self.buffer.make_contiguous()
+    }
+
+    /// Buffers up to `n` items and returns them as a single mutable slice.
+    ///
+    /// Unlike [`as_mut_slices`](Self::as_mut_slices), the result is always one slice: the
+    /// buffer is compacted with [`VecDeque::make_contiguous`] if it wraps.
+    ///
+    /// # Returns
+    /// The first `n` items, or fewer if the iterator ended early.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([3, 1, 2, 0].into_iter());
+    /// iter.peek_contiguous_mut(3).sort();
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 0]);
+    /// ```
+    pub fn peek_contiguous_mut(&mut self, n: usize) -> &mut [I::Item] {
+        if let Some(last) = n.checked_sub(1) {
+            let _ = self.peek_nth(last);
+        }
+
+        let len = n.min(self.buffer.len());
+        &mut self.buffer.make_contiguous()[..len]
+    }
+
     /// Clears all buffered items.
     #[inline]
     pub fn clear_peeked(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.rebuffer.cleared = self.buffer.len();
+        }
         self.buffer.clear();
     }
 
+    /// Returns the number of items the peek buffer can hold without reallocating.
+    #[inline]
+    pub fn peeked_capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Reserves room for at least `additional` more buffered items.
+    ///
+    /// Useful when the required lookahead depth is only known after construction;
+    /// see [`VecDeque::reserve`].
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// iter.reserve(16);
+    /// assert!(iter.peeked_capacity() >= 16);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
+    }
+
+    /// Shrinks the peek buffer's capacity as much as possible, releasing memory left over
+    /// from a deep peek. See [`VecDeque::shrink_to_fit`].
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
+    /// Returns how many times `clear_peeked` was immediately followed by re-peeking
+    /// an index that had just been discarded.
+    ///
+    /// Such a pattern usually means lookahead is being thrown away while it is still
+    /// needed, which can make a parser quadratic. Only available in debug builds.
+    #[cfg(debug_assertions)]
+    pub fn debug_rebuffer_count(&self) -> usize {
+        self.rebuffer.count
+    }
+
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn track_repeek(&mut self, n: usize) {
+        if n < self.rebuffer.cleared {
+            self.rebuffer.count += 1;
+        }
+        self.rebuffer.cleared = 0;
+    }
+
+    /// Pulls the next unbuffered item: from the iterator, then the `peek_last` slot.
+    #[inline]
+    fn pull(&mut self) -> Option<I::Item> {
+        self.iter.next().or_else(|| self.tail.take())
+    }
+
+    /// Returns the number of items held outside the inner iterator.
+    #[inline]
+    fn held_len(&self) -> usize {
+        self.buffer.len() + self.tail.is_some() as usize
+    }
+
     /// Discards the first `until` buffered items.
     #[inline]
     pub fn drain_peeked(&mut self, until: usize) {
@@ -449,7 +1506,129 @@ impl<I: Iterator> PeekN<I> {
             until,
             self.buffer.len()
         );
-        self.buffer.drain(..until);
+        self.buffer.drain(..until);
+    }
+
+    /// Consumes and discards up to `n` items, returning how many were skipped.
+    ///
+    /// Buffered items are removed first; if `n` exceeds the buffered count, the rest are
+    /// pulled from the inner iterator. Unlike [`drain_peeked`](Self::drain_peeked), this
+    /// advances the underlying iterator. The result is less than `n` only if the iterator
+    /// ended.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..5);
+    /// let _ = iter.peek_nth(1);
+    /// assert_eq!(iter.skip_peeked(3), 3);
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.skip_peeked(10), 1);
+    /// ```
+    pub fn skip_peeked(&mut self, n: usize) -> usize {
+        let buffered = n.min(self.buffer.len());
+        self.buffer.drain(..buffered);
+
+        let mut skipped = buffered;
+        while skipped < n && self.pull().is_some() {
+            skipped += 1;
+        }
+        skipped
+    }
+
+    /// Removes the first `until` buffered items and yields them in order.
+    ///
+    /// Like [`drain_peeked`](Self::drain_peeked), `until` is clamped to the number of
+    /// buffered items. Items not yet yielded are still removed when the iterator is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..5);
+    /// let _ = iter.peek_nth(3);
+    /// assert_eq!(iter.drain_peeked_iter(2).collect::<Vec<_>>(), vec![0, 1]);
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    pub fn drain_peeked_iter(&mut self, until: usize) -> impl Iterator<Item = I::Item> + '_ {
+        let until = until.min(self.buffer.len());
+        self.buffer.drain(..until)
+    }
+
+    /// Removes and returns all buffered items in order, leaving the inner iterator untouched.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..5);
+    /// let _ = iter.peek_nth(2);
+    /// assert_eq!(iter.collect_peeked(), vec![0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn collect_peeked(&mut self) -> Vec<I::Item> {
+        self.buffer.drain(..).collect()
+    }
+
+    /// Reverses the order of the buffered items in place.
+    ///
+    /// Only elements that have already been peeked are affected; the underlying
+    /// iterator is left untouched and resumes after the reversed items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// let _ = iter.peek_nth(2);
+    /// iter.reverse_peeked();
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    #[inline]
+    pub fn reverse_peeked(&mut self) {
+        self.buffer.make_contiguous().reverse();
+    }
+
+    /// Rotates the buffered items left by `mid` positions.
+    ///
+    /// Only elements that have already been peeked are affected: the item at index `mid`
+    /// becomes the next one returned, and the first `mid` items move to the end of the
+    /// buffer, ahead of the unread part of the iterator.
+    ///
+    /// # Panics
+    /// Panics if `mid > peeked_len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..);
+    /// let _ = iter.peek_nth(3);
+    /// iter.rotate_peeked_left(1);
+    /// assert_eq!(iter.by_ref().take(5).collect::<Vec<_>>(), vec![1, 2, 3, 0, 4]);
+    /// ```
+    pub fn rotate_peeked_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.buffer.len(),
+            "rotate_peeked_left: mid ({mid}) exceeds peeked length ({})",
+            self.buffer.len()
+        );
+        self.buffer.make_contiguous().rotate_left(mid);
+    }
+
+    /// Rotates the buffered items right by `k` positions.
+    ///
+    /// The last `k` buffered items become the next ones returned. The underlying iterator
+    /// is left untouched.
+    ///
+    /// # Panics
+    /// Panics if `k > peeked_len()`.
+    pub fn rotate_peeked_right(&mut self, k: usize) {
+        assert!(
+            k <= self.buffer.len(),
+            "rotate_peeked_right: k ({k}) exceeds peeked length ({})",
+            self.buffer.len()
+        );
+        self.buffer.make_contiguous().rotate_right(k);
     }
 
     /// Consumes and yields items while the predicate returns `true`.
@@ -481,6 +1660,50 @@ impl<I: Iterator> PeekN<I> {
         })
     }
 
+    /// Consumes and yields items while `pred` returns `true`, leaving the first
+    /// non-matching item peekable.
+    ///
+    /// This behaves exactly like `itertools`' `peeking_take_while`, to ease migration: the
+    /// rejected item is not consumed, so the next `peek()` or `next()` returns it.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("abc123".chars());
+    /// let letters: String = iter.peeking_take_while(|c| c.is_alphabetic()).collect();
+    /// assert_eq!(letters, "abc");
+    /// assert_eq!(iter.peek(), Some(&'1'));
+    /// ```
+    pub fn peeking_take_while(
+        &mut self,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl Iterator<Item = I::Item> {
+        core::iter::from_fn(move || self.next_if(&mut pred))
+    }
+
+    /// Consumes and returns the items before the first one matching `pred`, consuming the
+    /// separator as well.
+    ///
+    /// The items are peeked first, so if no separator is found before the end of the stream,
+    /// nothing is consumed and `None` is returned; all scanned items stay in the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([1, 2, 0, 3].into_iter());
+    /// assert_eq!(iter.split_once(|&x| x == 0), Some(vec![1, 2]));
+    /// assert_eq!(iter.split_once(|&x| x == 0), None);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn split_once(&mut self, mut pred: impl FnMut(&I::Item) -> bool) -> Option<Vec<I::Item>> {
+        let n = self.while_peek(|item| !pred(item));
+        self.peek_nth(n)?;
+
+        let head = self.buffer.drain(..n).collect();
+        self.buffer.pop_front();
+        Some(head)
+    }
+
     /// Counts how many items satisfy the predicate without consuming them.
     ///
     /// This method peeks at the `n`-th item in the buffer using `peek_nth(count)`,
@@ -504,6 +1727,522 @@ impl<I: Iterator> PeekN<I> {
 
         count
     }
+
+    /// Clones the matching prefix into a `Vec`, leaving it buffered.
+    ///
+    /// Works like [`while_peek`](Self::while_peek), but also returns copies of the matching
+    /// items, so no borrow of `self` is held afterwards.
+    ///
+    /// # Arguments
+    /// * `func` - A predicate to test each peeked item.
+    ///
+    /// # Returns
+    /// The leading items that satisfy the predicate, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..10);
+    /// assert_eq!(iter.peek_while_vec(|&x| x < 3), vec![0, 1, 2]);
+    /// assert_eq!(iter.next(), Some(0));
+    /// ```
+    pub fn peek_while_vec(&mut self, func: impl FnMut(&I::Item) -> bool) -> Vec<I::Item>
+    where
+        I::Item: Clone,
+    {
+        let count = self.while_peek(func);
+        self.buffer.range(..count).cloned().collect()
+    }
+
+    /// Counts how many items satisfy the predicate, given the previous item, without consuming them.
+    ///
+    /// Works like [`while_peek`](Self::while_peek), but the predicate also receives
+    /// the item just before the current one (`None` for the first item).
+    /// This allows context-sensitive decisions such as stopping at an unescaped quote.
+    ///
+    /// # Arguments
+    /// * `pred` - A predicate called with the previous item and the current item.
+    ///
+    /// # Returns
+    /// The number of consecutive peeked elements that satisfy the predicate.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(r#"ab\"c"d"#.chars());
+    /// let len = iter.peek_while_with_prev(|prev, &c| c != '"' || prev == Some(&'\\'));
+    /// assert_eq!(len, 5);
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    pub fn peek_while_with_prev(
+        &mut self,
+        mut pred: impl FnMut(Option<&I::Item>, &I::Item) -> bool,
+    ) -> usize {
+        let mut count = 0;
+        while self.peek_nth(count).is_some() {
+            let prev = count.checked_sub(1).map(|i| &self.buffer[i]);
+            if pred(prev, &self.buffer[count]) {
+                count += 1;
+            } else {
+                break;
+            }
+        }
+
+        count
+    }
+
+    /// Finds the first item matching `pred` without consuming anything.
+    ///
+    /// Already-buffered items are checked first; the buffer is then filled one item at a
+    /// time until `pred` returns `true`. On an infinite iterator without a match this never
+    /// returns; use [`peek_find_within`](Self::peek_find_within) to bound the search.
+    ///
+    /// # Returns
+    /// The zero-based lookahead index and a reference to the matching item, or `None` if
+    /// the iterator is exhausted first.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("key=value".chars());
+    /// assert_eq!(iter.peek_find(|&c| c == '='), Some((3, &'=')));
+    /// assert_eq!(iter.next(), Some('k'));
+    /// ```
+    pub fn peek_find(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Option<(usize, &I::Item)> {
+        self.peek_find_within(usize::MAX, pred)
+    }
+
+    /// Finds the first item matching `pred` among the next `limit` items, without consuming
+    /// anything.
+    ///
+    /// Stops after inspecting `limit` items, so it is safe on infinite iterators.
+    ///
+    /// # Returns
+    /// The zero-based lookahead index and a reference to the matching item, or `None` if no
+    /// match is found within `limit` items or before the iterator is exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..);
+    /// assert_eq!(iter.peek_find_within(5, |&x| x % 7 == 0), None);
+    /// assert_eq!(iter.peek_find_within(10, |&x| x % 7 == 0), Some((6, &7)));
+    /// ```
+    pub fn peek_find_within(
+        &mut self,
+        limit: usize,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> Option<(usize, &I::Item)> {
+        let mut n = 0;
+        while n < limit && self.peek_nth(n).is_some_and(|item| !pred(item)) {
+            n += 1;
+        }
+
+        if n == limit {
+            return None;
+        }
+        self.peek_nth(n).map(|item| (n, item))
+    }
+
+    /// Finds the item closing the delimited region that starts at the next item, without
+    /// consuming anything.
+    ///
+    /// The next item must satisfy `open`. Items are then buffered while tracking the nesting
+    /// depth until the matching `close`. An item satisfying both predicates is treated as a
+    /// closer once a region is open.
+    ///
+    /// # Returns
+    /// The zero-based lookahead index of the matching closer, or `None` if the next item is
+    /// not an opener or the iterator ends while the region is still open.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("(a(b))c".chars());
+    /// assert_eq!(iter.peek_balanced(|&c| c == '(', |&c| c == ')'), Some(5));
+    /// assert_eq!(iter.next(), Some('('));
+    /// ```
+    pub fn peek_balanced(
+        &mut self,
+        open: impl Fn(&I::Item) -> bool,
+        close: impl Fn(&I::Item) -> bool,
+    ) -> Option<usize> {
+        if !open(self.peek()?) {
+            return None;
+        }
+
+        let mut depth = 1usize;
+        let mut n = 1;
+        while let Some(item) = self.peek_nth(n) {
+            if close(item) {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(n);
+                }
+            } else if open(item) {
+                depth += 1;
+            }
+            n += 1;
+        }
+
+        None
+    }
+
+    /// Returns the zero-based lookahead index of the first item matching `pred`, without
+    /// consuming anything.
+    ///
+    /// Like [`peek_find`](Self::peek_find), but holds no borrow afterwards. The scanned
+    /// items stay buffered, so `peek_nth` on the returned index does not pull again. On an
+    /// infinite iterator without a match this never returns; use
+    /// [`peek_position_within`](Self::peek_position_within) to bound the search.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("key=value".chars());
+    /// assert_eq!(iter.peek_position(|&c| c == '='), Some(3));
+    /// assert_eq!(iter.peek_position(|&c| c == '#'), None);
+    /// ```
+    pub fn peek_position(&mut self, pred: impl FnMut(&I::Item) -> bool) -> Option<usize> {
+        self.peek_find(pred).map(|(n, _)| n)
+    }
+
+    /// Like [`peek_position`](Self::peek_position), but inspects at most `limit` items and
+    /// returns `None` if none of them match.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..);
+    /// assert_eq!(iter.peek_position_within(5, |&x| x % 7 == 0), None);
+    /// assert_eq!(iter.peek_position_within(10, |&x| x % 7 == 0), Some(6));
+    /// ```
+    pub fn peek_position_within(
+        &mut self,
+        limit: usize,
+        pred: impl FnMut(&I::Item) -> bool,
+    ) -> Option<usize> {
+        self.peek_find_within(limit, pred).map(|(n, _)| n)
+    }
+
+    /// Returns the upcoming items up to and including the first one matching `pred`,
+    /// without consuming anything.
+    ///
+    /// The buffer is filled until `pred` returns `true`. If the iterator ends first, all
+    /// remaining items are returned. On an infinite iterator without a match this never
+    /// returns; use [`peek_until_within`](Self::peek_until_within) to bound the search.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn("ab\ncd".chars());
+    /// assert_eq!(iter.peek_until(|&c| c == '\n').collect::<String>(), "ab\n");
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    pub fn peek_until(
+        &mut self,
+        pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + ExactSizeIterator {
+        self.peek_until_within(usize::MAX, pred)
+    }
+
+    /// Like [`peek_until`](Self::peek_until), but inspects at most `limit` items.
+    ///
+    /// If no match is found among the next `limit` items, those items are returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..);
+    /// assert_eq!(iter.peek_until_within(3, |&x| x == 10).count(), 3);
+    /// assert_eq!(iter.peek_until_within(20, |&x| x == 10).last(), Some(&10));
+    /// ```
+    pub fn peek_until_within(
+        &mut self,
+        limit: usize,
+        pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl DoubleEndedIterator<Item = &I::Item> + ExactSizeIterator {
+        let end = self.until_len(limit, pred);
+        self.buffer.range(..end)
+    }
+
+    /// Consumes and yields the items up to and including the first one matching `pred`.
+    ///
+    /// The iterator is left positioned right after the match. If there is no match, all
+    /// remaining items are yielded.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([1, 2, 0, 3, 0].into_iter());
+    /// assert_eq!(iter.next_until(|&x| x == 0).collect::<Vec<_>>(), vec![1, 2, 0]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn next_until(
+        &mut self,
+        pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl DoubleEndedIterator<Item = I::Item> + ExactSizeIterator {
+        let end = self.until_len(usize::MAX, pred);
+        self.buffer.drain(..end)
+    }
+
+    /// Returns the length of the prefix ending at the first match of `pred`, capped at `limit`.
+    fn until_len(&mut self, limit: usize, pred: impl FnMut(&I::Item) -> bool) -> usize {
+        match self.peek_find_within(limit, pred) {
+            Some((n, _)) => n + 1,
+            None => self.buffer.len().min(limit),
+        }
+    }
+
+    /// Peeks at the next item and counts how many equal items start the stream, without
+    /// consuming anything.
+    ///
+    /// # Returns
+    /// The next item and the length of its run (at least `1`), or `None` if the iterator
+    /// is exhausted. The whole run is left in the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([5, 5, 5, 6].into_iter());
+    /// assert_eq!(iter.peek_with_run(), Some((&5, 3)));
+    /// assert_eq!(iter.next(), Some(5));
+    /// ```
+    pub fn peek_with_run(&mut self) -> Option<(&I::Item, usize)>
+    where
+        I::Item: PartialEq,
+    {
+        self.peek()?;
+        let mut len = 1;
+        while self.peek_nth(len).is_some() && self.buffer[len] == self.buffer[0] {
+            len += 1;
+        }
+        Some((&self.buffer[0], len))
+    }
+
+    /// Previews how the remaining items group into runs of equal keys, without consuming
+    /// anything.
+    ///
+    /// The whole remaining stream is buffered up front (its length is known from
+    /// [`ExactSizeIterator`]), and `key` is called once per item.
+    ///
+    /// # Returns
+    /// An iterator over `(key, run_length)` pairs for each maximal run of consecutive items
+    /// with equal keys.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([1, 1, 2, 3, 3, 3].into_iter());
+    /// let runs: Vec<_> = iter.peek_group_runs(|&x| x * 10).collect();
+    /// assert_eq!(runs, vec![(10, 2), (20, 1), (30, 3)]);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn peek_group_runs<K, F>(&mut self, key: F) -> impl Iterator<Item = (K, usize)>
+    where
+        I: ExactSizeIterator,
+        K: PartialEq,
+        F: FnMut(&I::Item) -> K,
+    {
+        if let Some(last) = self.len().checked_sub(1) {
+            let _ = self.peek_nth(last);
+        }
+
+        let mut keys = self.buffer.iter().map(key).peekable();
+        core::iter::from_fn(move || {
+            let current = keys.next()?;
+            let mut len = 1;
+            while keys.next_if_eq(&current).is_some() {
+                len += 1;
+            }
+            Some((current, len))
+        })
+    }
+
+    /// Consumes the iterator and yields each maximal run of equal items with its length.
+    ///
+    /// # Returns
+    /// An iterator over `(value, run_length)` pairs, where `value` is the first item of the run.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let runs: Vec<_> = peekn([1, 1, 2, 3, 3, 3].into_iter()).run_length_encode().collect();
+    /// assert_eq!(runs, vec![(1, 2), (2, 1), (3, 3)]);
+    /// ```
+    pub fn run_length_encode(mut self) -> impl Iterator<Item = (I::Item, usize)>
+    where
+        I::Item: PartialEq,
+    {
+        core::iter::from_fn(move || {
+            let value = self.next()?;
+            let mut len = 1;
+            while self.next_if_eq(&value).is_some() {
+                len += 1;
+            }
+            Some((value, len))
+        })
+    }
+
+    /// Consumes the iterator like [`Iterator::scan`], but also passes `f` a peek at the
+    /// following item.
+    ///
+    /// `f` receives the mutable state, the current item and the next item (`None` for the
+    /// last one). Iteration ends when `f` returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let diffs: Vec<_> = peekn([1, 3, 6].into_iter())
+    ///     .scan_with_peek((), |_, x, next| next.map(|n| n - x))
+    ///     .collect();
+    /// assert_eq!(diffs, vec![2, 3]);
+    /// ```
+    pub fn scan_with_peek<St, R>(
+        mut self,
+        init: St,
+        mut f: impl FnMut(&mut St, I::Item, Option<&I::Item>) -> Option<R>,
+    ) -> impl Iterator<Item = R> {
+        let mut state = init;
+        core::iter::from_fn(move || {
+            let item = self.next()?;
+            f(&mut state, item, self.peek())
+        })
+    }
+
+    /// Consumes the iterator and yields consecutive, non-overlapping chunks of `n` items.
+    ///
+    /// Already-peeked items are yielded first. The last chunk may be shorter than `n`
+    /// if the stream length is not a multiple of `n`; an empty stream yields no chunks.
+    ///
+    /// # Panics
+    /// Panics if `n == 0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let chunks: Vec<_> = peekn(0..7).chunks(3).collect();
+    /// assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    /// ```
+    pub fn chunks(mut self, n: usize) -> impl Iterator<Item = Vec<I::Item>> {
+        assert!(n != 0, "chunks: chunk size must be non-zero");
+
+        core::iter::from_fn(move || {
+            let chunk: Vec<_> = self.by_ref().take(n).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+}
+
+impl<I, T, E> PeekN<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    /// Peeks at the `n`-th `Ok` value of a fallible stream, stopping at the first error.
+    ///
+    /// Items are buffered up to index `n`. If an `Err` is found before (or at) index `n`,
+    /// buffering stops there and the error is returned by reference; nothing is consumed.
+    ///
+    /// # Returns
+    /// - `Ok(Some(&value))` if the items up to `n` are all `Ok`.
+    /// - `Ok(None)` if the iterator ends before index `n`.
+    /// - `Err(&error)` for the first error within the first `n + 1` items.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([Ok(1), Ok(2), Err("x"), Ok(3)].into_iter());
+    /// assert_eq!(iter.peek_nth_ok(1), Ok(Some(&2)));
+    /// assert_eq!(iter.peek_nth_ok(3), Err(&"x"));
+    /// assert_eq!(iter.peeked_len(), 3);
+    /// ```
+    pub fn peek_nth_ok(&mut self, n: usize) -> Result<Option<&T>, &E> {
+        if n > crate::MAX_PEEK_INDEX {
+            return Ok(None);
+        }
+
+        for i in 0..=n {
+            match self.peek_nth(i) {
+                None => return Ok(None),
+                Some(Err(_)) => break,
+                Some(Ok(_)) => {}
+            }
+        }
+
+        match self
+            .buffer
+            .iter()
+            .take(n + 1)
+            .find_map(|item| item.as_ref().err())
+        {
+            Some(err) => Err(err),
+            None => Ok(self.buffer[n].as_ref().ok()),
+        }
+    }
+}
+
+impl<I> PeekN<I>
+where
+    I: Iterator,
+    I::Item: Copy + PartialOrd + Add<Output = I::Item>,
+{
+    /// Consumes items while their running sum stays within `limit`.
+    ///
+    /// The first item that would push the sum above `limit` is left in the buffer,
+    /// so it is returned by the next call to `next()`.
+    ///
+    /// # Returns
+    /// The consumed items, in order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn([3, 3, 3, 3].into_iter());
+    /// assert_eq!(iter.take_while_sum(7), vec![3, 3]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    pub fn take_while_sum(&mut self, limit: I::Item) -> Vec<I::Item> {
+        let mut taken = Vec::new();
+        let mut sum: Option<I::Item> = None;
+
+        while let Some(item) = self.next_if(|&x| sum.map_or(x, |s| s + x) <= limit) {
+            sum = Some(sum.map_or(item, |s| s + item));
+            taken.push(item);
+        }
+
+        taken
+    }
+}
+
+impl<I: DoubleEndedIterator> PeekN<I> {
+    /// Peeks at the final item without consuming the rest of the iterator.
+    ///
+    /// The first call takes one item from the back of the inner iterator with
+    /// `next_back()` and keeps it in a dedicated tail slot. `next()` still yields it,
+    /// exactly once, after everything else. The tail item is included in `len()` and
+    /// `size_hint()`, so an [`ExactSizeIterator`] length is unchanged by this call.
+    ///
+    /// # Returns
+    /// The last item, or `None` if no items remain.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(0..4);
+    /// assert_eq!(iter.peek_last(), Some(&3));
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn peek_last(&mut self) -> Option<&I::Item> {
+        if self.tail.is_none() {
+            self.tail = self.iter.next_back();
+        }
+
+        match &self.tail {
+            Some(item) => Some(item),
+            None => self.buffer.back(),
+        }
+    }
 }
 
 /// Creates a `PeekN` from a `Peekable` iterator, discarding its current peek state.
@@ -537,3 +2276,21 @@ impl<I: Iterator> PeekN<Peekable<I>> {
 pub fn peekn<I: Iterator>(iter: I) -> PeekN<I> {
     PeekN::new(iter)
 }
+
+/// A `PeekN` over a type-erased, boxed iterator.
+///
+/// Useful when the stream source is chosen at runtime, e.g. by a plugin.
+pub type BoxedPeekN<T> = PeekN<Box<dyn Iterator<Item = T>>>;
+
+/// Wraps a boxed iterator into a [`BoxedPeekN`].
+///
+/// # Examples
+/// ```
+/// use peeknth::peekn_boxed;
+/// let mut iter = peekn_boxed(Box::new((0..).map(|x| x * 2)));
+/// assert_eq!(iter.peek_nth(2), Some(&4));
+/// assert_eq!(iter.next(), Some(0));
+/// ```
+pub fn peekn_boxed<T>(iter: Box<dyn Iterator<Item = T>>) -> BoxedPeekN<T> {
+    PeekN::new(iter)
+}
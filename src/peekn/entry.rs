@@ -0,0 +1,90 @@
+use alloc::collections::VecDeque;
+
+/// A view into a single lookahead slot of a [`PeekN`](crate::PeekN).
+///
+/// Created by [`PeekN::front_entry`](crate::PeekN::front_entry).
+#[derive(Debug)]
+pub enum PeekEntry<'a, T> {
+    /// The slot holds a peeked item.
+    Occupied(&'a mut T),
+    /// The iterator ended before reaching the slot.
+    Vacant(VacantEntry<'a, T>),
+}
+
+/// A lookahead slot past the end of the iterator.
+///
+/// Part of [`PeekEntry`].
+#[derive(Debug)]
+pub struct VacantEntry<'a, T> {
+    pub(crate) buffer: &'a mut VecDeque<T>,
+    pub(crate) index: usize,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Returns the index of the slot.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns `true` if the slot directly follows the last peeked item,
+    /// so that a value can be appended there.
+    pub fn is_next_slot(&self) -> bool {
+        self.index == self.buffer.len()
+    }
+
+    /// Appends `value` at the slot and returns a mutable reference to it.
+    ///
+    /// # Panics
+    /// Panics if the slot is not the next free slot, since that would
+    /// leave a gap in the buffer.
+    pub fn insert(self, value: T) -> &'a mut T {
+        assert!(
+            self.is_next_slot(),
+            "VacantEntry::insert: index {} is past the next free slot {}",
+            self.index,
+            self.buffer.len()
+        );
+        self.buffer.push_back(value);
+        &mut self.buffer[self.index]
+    }
+}
+
+impl<'a, T> PeekEntry<'a, T> {
+    /// Returns the occupied item, or appends `default` if the slot is vacant.
+    ///
+    /// # Panics
+    /// Panics if the slot is vacant and not the next free slot.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        match self {
+            PeekEntry::Occupied(item) => item,
+            PeekEntry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Returns the occupied item, or appends the result of `f` if the slot is vacant.
+    ///
+    /// # Panics
+    /// Panics if the slot is vacant and not the next free slot.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        match self {
+            PeekEntry::Occupied(item) => item,
+            PeekEntry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Calls `f` on the item if the slot is occupied.
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        match self {
+            PeekEntry::Occupied(item) => {
+                f(item);
+                PeekEntry::Occupied(item)
+            }
+            vacant => vacant,
+        }
+    }
+
+    /// Returns `true` if the slot holds a peeked item.
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, PeekEntry::Occupied(_))
+    }
+}
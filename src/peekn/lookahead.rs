@@ -0,0 +1,108 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+/// A read-only window over the next items of a [`PeekN`](crate::PeekN).
+///
+/// Created by [`PeekN::lookahead`](crate::PeekN::lookahead). The window borrows the peek
+/// buffer, so all lookahead within a scope can go through it without re-borrowing the
+/// iterator.
+pub struct Lookahead<'a, I: Iterator> {
+    pub(crate) buffer: &'a VecDeque<I::Item>,
+    pub(crate) len: usize,
+}
+
+impl<'a, I: Iterator> Lookahead<'a, I> {
+    /// Returns the `i`-th item of the window, or `None` if `i >= len()`.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&'a I::Item> {
+        if i < self.len {
+            self.buffer.get(i)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of items in the window.
+    ///
+    /// This is smaller than the requested size if the iterator ended early.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the window holds no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the items of the window.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a I::Item> + ExactSizeIterator {
+        self.buffer.range(..self.len)
+    }
+
+    /// Returns the window as a slice if it is stored contiguously in the buffer.
+    pub fn as_slice(&self) -> Option<&'a [I::Item]> {
+        let (head, _) = self.buffer.as_slices();
+        head.get(..self.len)
+    }
+
+    /// Copies the window into a new `Vec`, in order.
+    ///
+    /// Unlike [`as_slice`](Self::as_slice), this also works when the window wraps
+    /// around the end of the buffer.
+    pub fn to_vec(&self) -> Vec<I::Item>
+    where
+        I::Item: Clone,
+    {
+        let (head, tail) = self.segments();
+        let mut out = Vec::with_capacity(self.len);
+        out.extend_from_slice(head);
+        out.extend_from_slice(tail);
+        out
+    }
+
+    /// Clones the window into the front of `out`, in order.
+    ///
+    /// # Returns
+    /// The number of items written, which is the smaller of `len()` and `out.len()`.
+    pub fn copy_into(&self, out: &mut [I::Item]) -> usize
+    where
+        I::Item: Clone,
+    {
+        let (head, tail) = self.segments();
+        let written = self.len.min(out.len());
+        let split = head.len().min(written);
+
+        out[..split].clone_from_slice(&head[..split]);
+        out[split..written].clone_from_slice(&tail[..written - split]);
+        written
+    }
+
+    /// Returns the two contiguous parts of the window, in order.
+    fn segments(&self) -> (&'a [I::Item], &'a [I::Item]) {
+        let (head, tail) = self.buffer.as_slices();
+        if self.len <= head.len() {
+            (&head[..self.len], &[])
+        } else {
+            (head, &tail[..self.len - head.len()])
+        }
+    }
+}
+
+impl<I: Iterator> Clone for Lookahead<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: Iterator> Copy for Lookahead<'_, I> {}
+
+impl<I> core::fmt::Debug for Lookahead<'_, I>
+where
+    I: Iterator,
+    I::Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -1,9 +1,33 @@
+#[cfg(feature = "std")]
+mod bytes;
+#[cfg(feature = "alloc")]
+mod checksum;
 #[cfg(feature = "alloc")]
 mod core;
+#[cfg(feature = "alloc")]
+mod entry;
+#[cfg(feature = "alloc")]
+mod lookahead;
+#[cfg(feature = "alloc")]
+mod peekmut;
+#[cfg(feature = "alloc")]
+mod scan;
 
+mod range;
 mod sizedpeekn;
 
 #[cfg(feature = "alloc")]
-pub use core::{PeekN, peekn};
+pub use checksum::Checksummed;
+#[cfg(feature = "alloc")]
+pub use core::{BoxedPeekN, PeekN, peekn, peekn_boxed};
+#[cfg(feature = "alloc")]
+pub use entry::{PeekEntry, VacantEntry};
+#[cfg(feature = "alloc")]
+pub use lookahead::Lookahead;
+#[cfg(feature = "alloc")]
+pub use peekmut::PeekMut;
+#[cfg(feature = "alloc")]
+pub use scan::ScanPeeked;
 
+pub use range::{PeekRange, PeekRangeMut};
 pub use sizedpeekn::{SizedPeekN, sizedpeekn};
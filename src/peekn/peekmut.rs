@@ -0,0 +1,61 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::PeekN;
+
+/// A guard granting mutable access to the next item of a [`PeekN`].
+///
+/// Created by [`PeekN::peek_mut_guard`]. The guard dereferences to the peeked item,
+/// which stays buffered so that edits are visible to the following `next()`.
+pub struct PeekMut<'a, I: Iterator> {
+    pub(crate) peekn: &'a mut PeekN<I>,
+}
+
+impl<I: Iterator> PeekMut<'_, I> {
+    /// Consumes the guard and the peeked item, returning the item with any edits applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::peekn;
+    /// let mut iter = peekn(1..4);
+    /// let mut guard = iter.peek_mut_guard().unwrap();
+    /// *guard *= 10;
+    /// assert_eq!(guard.pop(), 10);
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    pub fn pop(self) -> I::Item {
+        self.peekn
+            .buffer
+            .pop_front()
+            .expect("PeekMut guard over empty buffer")
+    }
+}
+
+impl<I: Iterator> Deref for PeekMut<'_, I> {
+    type Target = I::Item;
+
+    fn deref(&self) -> &Self::Target {
+        self.peekn
+            .buffer
+            .front()
+            .expect("PeekMut guard over empty buffer")
+    }
+}
+
+impl<I: Iterator> DerefMut for PeekMut<'_, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.peekn
+            .buffer
+            .front_mut()
+            .expect("PeekMut guard over empty buffer")
+    }
+}
+
+impl<I> core::fmt::Debug for PeekMut<'_, I>
+where
+    I: Iterator,
+    I::Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PeekMut").field(&**self).finish()
+    }
+}
@@ -0,0 +1,126 @@
+use core::{iter::FusedIterator, ops::Range, slice};
+
+/// An iterator over a peeked range of a [`PeekN`](crate::PeekN) or
+/// [`SizedPeekN`](crate::SizedPeekN).
+///
+/// Created by `peek_range`. The items are yielded in order from the (at most two)
+/// contiguous parts of the peek buffer.
+#[derive(Debug)]
+pub struct PeekRange<'a, T> {
+    head: slice::Iter<'a, T>,
+    tail: slice::Iter<'a, T>,
+}
+
+impl<'a, T> PeekRange<'a, T> {
+    /// Selects `range` from a buffer stored as the two slices `(head, tail)`.
+    pub(crate) fn new((head, tail): (&'a [T], &'a [T]), range: Range<usize>) -> Self {
+        let split = head.len();
+        let (head, tail) = if range.end <= split {
+            (&head[range], &[][..])
+        } else if range.start >= split {
+            (&[][..], &tail[range.start - split..range.end - split])
+        } else {
+            (&head[range.start..], &tail[..range.end - split])
+        };
+
+        PeekRange {
+            head: head.iter(),
+            tail: tail.iter(),
+        }
+    }
+}
+
+impl<T> Clone for PeekRange<'_, T> {
+    fn clone(&self) -> Self {
+        PeekRange {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for PeekRange<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head.next().or_else(|| self.tail.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for PeekRange<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail.next_back().or_else(|| self.head.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for PeekRange<'_, T> {
+    fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+}
+
+impl<T> FusedIterator for PeekRange<'_, T> {}
+
+/// A mutable iterator over a peeked range of a [`PeekN`](crate::PeekN) or
+/// [`SizedPeekN`](crate::SizedPeekN).
+///
+/// Created by `peek_range_mut`.
+#[derive(Debug)]
+pub struct PeekRangeMut<'a, T> {
+    head: slice::IterMut<'a, T>,
+    tail: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> PeekRangeMut<'a, T> {
+    /// Selects `range` from a buffer stored as the two slices `(head, tail)`.
+    pub(crate) fn new((head, tail): (&'a mut [T], &'a mut [T]), range: Range<usize>) -> Self {
+        let split = head.len();
+        let (head, tail) = if range.end <= split {
+            (&mut head[range], &mut [][..])
+        } else if range.start >= split {
+            (
+                &mut [][..],
+                &mut tail[range.start - split..range.end - split],
+            )
+        } else {
+            (&mut head[range.start..], &mut tail[..range.end - split])
+        };
+
+        PeekRangeMut {
+            head: head.iter_mut(),
+            tail: tail.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for PeekRangeMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head.next().or_else(|| self.tail.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for PeekRangeMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail.next_back().or_else(|| self.head.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for PeekRangeMut<'_, T> {
+    fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+}
+
+impl<T> FusedIterator for PeekRangeMut<'_, T> {}
@@ -0,0 +1,52 @@
+use core::iter::FusedIterator;
+
+use crate::PeekN;
+
+/// A lazy, non-consuming scan over the upcoming items of a [`PeekN`].
+///
+/// Created by [`PeekN::scan_peeked`]. Each `next()` buffers at most one more item from the
+/// inner iterator and yields a clone of it. Everything scanned stays buffered, so the
+/// parent `PeekN` still returns those items from its own `next()` afterwards.
+///
+/// Items are yielded by value because the buffer may reallocate while growing, which
+/// would invalidate references handed out by earlier calls.
+pub struct ScanPeeked<'a, I: Iterator> {
+    pub(crate) peekn: &'a mut PeekN<I>,
+    pub(crate) index: usize,
+}
+
+impl<I> Iterator for ScanPeeked<'_, I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.peekn.peek_nth(self.index)?.clone();
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered =
+            self.peekn.buffer.len().saturating_sub(self.index) + self.peekn.tail.is_some() as usize;
+        crate::util::add_size_hint(self.peekn.iter.size_hint(), buffered)
+    }
+}
+
+impl<I> FusedIterator for ScanPeeked<'_, I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I: Iterator> core::fmt::Debug for ScanPeeked<'_, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScanPeeked")
+            .field("index", &self.index)
+            .field("buffered", &self.peekn.buffer.len())
+            .finish()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::util::Buffer;
+use crate::{PeekBuffer, PeekRange, PeekRangeMut, error::PeekError, util::Buffer};
 
 use core::{
     iter::{FusedIterator, Iterator, Peekable},
@@ -16,8 +16,10 @@ use core::{
 ///
 /// # Type Parameters
 /// - `I`: The base iterator.
-/// - `I::Item`: Must implement `Copy`, since items are stored by value in the buffer.
+/// - `I::Item`: Stored by value in the buffer; buffered items are dropped along with the adapter.
 /// - `S`: Maximum number of front items that can be peeked.
+/// - `B`: The [`PeekBuffer`] storing the peeked items. Defaults to an inline ring buffer of
+///   capacity `S`; a spilling buffer such as `smallvec::SmallVec` lifts the limit.
 ///
 /// # Panics
 /// All `peek_nth(n)` and `peek_range`/`peek_range_mut` calls must satisfy `n < S`,
@@ -29,48 +31,61 @@ use core::{
 /// let mut peekn = sizedpeekn::<_, 3>(0..);
 /// assert_eq!(peekn.peek_nth(2), Some(&2));
 /// ```
-pub struct SizedPeekN<I, const S: usize>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "I: serde::Serialize, B: serde::Serialize",
+        deserialize = "I: serde::Deserialize<'de>, B: serde::Deserialize<'de>"
+    ))
+)]
+pub struct SizedPeekN<I, const S: usize, B = Buffer<<I as Iterator>::Item, S>>
 where
     I: Iterator,
-    I::Item: Copy,
 {
     pub(crate) iter: I,
-    pub(crate) buffer: Buffer<I::Item, S>,
+    pub(crate) buffer: B,
+    /// Number of items yielded by `next` so far.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) position: usize,
 }
 
-impl<I, const S: usize> Iterator for SizedPeekN<I, S>
+impl<I, const S: usize, B> Iterator for SizedPeekN<I, S, B>
 where
     I: Iterator,
-    I::Item: Copy,
+    B: PeekBuffer<I::Item>,
 {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(item) = self.buffer.pop_front() {
-            Some(item)
-        } else {
-            self.iter.next()
-        }
+        let item = self.buffer.pop_front().or_else(|| self.iter.next())?;
+        self.position += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        crate::util::add_size_hint(self.iter.size_hint(), self.buffer.len())
     }
 }
 
-impl<I, const S: usize> Clone for SizedPeekN<I, S>
+impl<I, const S: usize, B> Clone for SizedPeekN<I, S, B>
 where
     I: Iterator + Clone,
-    I::Item: Copy + Clone,
+    B: PeekBuffer<I::Item> + Clone,
 {
     fn clone(&self) -> Self {
         SizedPeekN {
             iter: self.iter.clone(),
             buffer: self.buffer.clone(),
+            position: self.position,
         }
     }
 }
 
-impl<I, const S: usize> core::fmt::Debug for SizedPeekN<I, S>
+impl<I, const S: usize, B> core::fmt::Debug for SizedPeekN<I, S, B>
 where
     I: Iterator + core::fmt::Debug,
-    I::Item: Copy + core::fmt::Debug,
+    B: PeekBuffer<I::Item> + core::fmt::Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SizedPeekN")
@@ -80,27 +95,38 @@ where
     }
 }
 
-impl<I, const S: usize> Eq for SizedPeekN<I, S>
+impl<I, const S: usize, B> Eq for SizedPeekN<I, S, B>
 where
     I: Iterator + Eq,
-    I::Item: Copy + Eq,
+    B: PeekBuffer<I::Item> + Eq,
 {
 }
 
-impl<I, const S: usize> PartialEq for SizedPeekN<I, S>
+impl<I, const S: usize, B> PartialEq for SizedPeekN<I, S, B>
 where
     I: Iterator + PartialEq,
-    I::Item: Copy + PartialEq,
+    B: PeekBuffer<I::Item> + PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         self.iter == other.iter && self.buffer == other.buffer
     }
 }
 
-impl<I, const S: usize> ExactSizeIterator for SizedPeekN<I, S>
+impl<I, const S: usize, B> core::hash::Hash for SizedPeekN<I, S, B>
+where
+    I: Iterator + core::hash::Hash,
+    B: PeekBuffer<I::Item> + core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.buffer.hash(state);
+    }
+}
+
+impl<I, const S: usize, B> ExactSizeIterator for SizedPeekN<I, S, B>
 where
     I: ExactSizeIterator,
-    I::Item: Copy,
+    B: PeekBuffer<I::Item>,
 {
     fn len(&self) -> usize {
         self.buffer.len() + self.iter.len()
@@ -110,7 +136,7 @@ where
 impl<I, const S: usize> From<Peekable<I>> for SizedPeekN<Peekable<I>, S>
 where
     I: Iterator,
-    I::Item: Clone + Copy,
+    I::Item: Clone,
 {
     fn from(mut peekable: Peekable<I>) -> Self {
         let buffer = Buffer::from_iter(peekable.peek().cloned());
@@ -118,29 +144,108 @@ where
         SizedPeekN {
             iter: peekable,
             buffer,
+            position: 0,
         }
     }
 }
 
-impl<I, const S: usize> FusedIterator for SizedPeekN<I, S>
+#[cfg(feature = "alloc")]
+impl<I: Iterator, const S: usize> TryFrom<crate::PeekN<I>> for SizedPeekN<I, S> {
+    type Error = crate::IntoSizedError<crate::PeekN<I>>;
+
+    /// Moves the peeked items of a `PeekN` into the fixed-size buffer.
+    ///
+    /// Fails with [`PeekError::BufferOverflow`] if more than `S` items are peeked, or with
+    /// [`PeekError::ReservedLast`] if the `PeekN` holds an item reserved by
+    /// [`peek_last`](crate::PeekN::peek_last), since that item has no place in a
+    /// `SizedPeekN`. On failure the original `PeekN` is handed back unchanged; its peeked
+    /// length is available from [`IntoSizedError::get_ref`](crate::IntoSizedError::get_ref).
+    fn try_from(value: crate::PeekN<I>) -> Result<Self, Self::Error> {
+        let error = if value.peeked_len() > S {
+            Some(PeekError::BufferOverflow { capacity: S })
+        } else if value.tail.is_some() {
+            Some(PeekError::ReservedLast)
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            return Err(crate::IntoSizedError {
+                source: value,
+                error,
+            });
+        }
+
+        Ok(SizedPeekN {
+            iter: value.iter,
+            buffer: Buffer::from_iter(value.buffer),
+            position: 0,
+        })
+    }
+}
+
+impl<I, const S: usize, B> FusedIterator for SizedPeekN<I, S, B>
 where
     I: FusedIterator,
-    I::Item: Copy,
+    B: PeekBuffer<I::Item>,
 {
 }
 
+#[cfg(feature = "itertools")]
+impl<I, const S: usize, B> itertools::PeekingNext for SizedPeekN<I, S, B>
+where
+    I: Iterator,
+    B: PeekBuffer<I::Item>,
+{
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        self.next_if(accept)
+    }
+}
+
 impl<I, const S: usize> SizedPeekN<I, S>
 where
     I: Iterator,
-    I::Item: Copy,
 {
+    /// The fixed capacity of the peek buffer, `S`.
+    pub const CAPACITY: usize = S;
+
     /// Creates a new `SizedPeekN` from the given iterator.
     ///
     /// The internal fixed-size peek buffer is initialized empty.
     pub fn new(iter: I) -> Self {
+        SizedPeekN::with_buffer(iter, Buffer::new())
+    }
+}
+
+impl<I, const S: usize, B> SizedPeekN<I, S, B>
+where
+    I: Iterator,
+    B: PeekBuffer<I::Item>,
+{
+    /// Creates a new `SizedPeekN` that stores its lookahead in `buffer`.
+    ///
+    /// Items already in `buffer` are treated as peeked: they are yielded first, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "smallvec")] {
+    /// use peeknth::SizedPeekN;
+    /// use smallvec::SmallVec;
+    ///
+    /// // Two items inline, spilling to the heap past that.
+    /// let mut iter = SizedPeekN::<_, 2, SmallVec<[i32; 2]>>::with_buffer(0..10, SmallVec::new());
+    /// assert_eq!(iter.peek_nth(5), Some(&5));
+    /// assert_eq!(iter.next(), Some(0));
+    /// # }
+    /// ```
+    pub fn with_buffer(iter: I, buffer: B) -> Self {
         SizedPeekN {
             iter,
-            buffer: Buffer::new(),
+            buffer,
+            position: 0,
         }
     }
 
@@ -158,7 +263,9 @@ where
     /// # Returns
     /// `Some(&item)` if available, otherwise `None`.
     pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
-        core::debug_assert!(n < usize::MAX, "peek_nth() with usize::MAX is likely a bug");
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.buffer.len() > n {
             return self.buffer.get(n);
@@ -186,10 +293,9 @@ where
     /// # Returns
     /// `Some(&mut item)` if available, otherwise `None`.
     pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
-        core::debug_assert!(
-            n < usize::MAX,
-            "peek_nth_mut() with usize::MAX is likely a bug"
-        );
+        if n > crate::MAX_PEEK_INDEX {
+            return None;
+        }
 
         if self.buffer.len() > n {
             return self.buffer.get_mut(n);
@@ -203,6 +309,81 @@ where
         self.buffer.get_mut(n)
     }
 
+    /// Peeks at the `n`-th item, reporting an out-of-capacity index as an error.
+    ///
+    /// Behaves like [`peek_nth`](Self::peek_nth), but returns an error instead of
+    /// panicking when `n` does not fit in the buffer.
+    ///
+    /// # Returns
+    /// `Err(PeekError::OverCapacity)` if `n >= S`, otherwise the result of `peek_nth(n)`.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::{PeekError, sizedpeekn};
+    ///
+    /// let mut iter = sizedpeekn::<_, 2>(0..);
+    /// assert_eq!(iter.try_peek_nth(1), Ok(Some(&1)));
+    /// assert_eq!(
+    ///     iter.try_peek_nth(2),
+    ///     Err(PeekError::OverCapacity { requested: 2, capacity: 2 })
+    /// );
+    /// ```
+    pub fn try_peek_nth(&mut self, n: usize) -> Result<Option<&I::Item>, PeekError> {
+        self.check_capacity(n)?;
+        Ok(self.peek_nth(n))
+    }
+
+    /// Mutably peeks at the `n`-th item, reporting an out-of-capacity index as an error.
+    ///
+    /// Behaves like [`peek_nth_mut`](Self::peek_nth_mut), but returns an error instead of
+    /// panicking when `n` does not fit in the buffer.
+    ///
+    /// # Returns
+    /// `Err(PeekError::OverCapacity)` if `n >= S`, otherwise the result of `peek_nth_mut(n)`.
+    pub fn try_peek_nth_mut(&mut self, n: usize) -> Result<Option<&mut I::Item>, PeekError> {
+        self.check_capacity(n)?;
+        Ok(self.peek_nth_mut(n))
+    }
+
+    fn check_capacity(&self, n: usize) -> Result<(), PeekError> {
+        let capacity = self.capacity();
+        if n >= capacity {
+            return Err(PeekError::OverCapacity {
+                requested: n,
+                capacity,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the `n`-th element, synthesizing it if the iterator ends
+    /// exactly at index `n`.
+    ///
+    /// If the `n`-th element can be peeked, it is returned as with `peek_nth_mut`. If the
+    /// iterator is exhausted and `n` is the next free slot (`n == peeked_len()`), the value
+    /// produced by `f` is appended to the buffer and returned. `f` is not called otherwise.
+    ///
+    /// # Panics
+    /// Panics if `n >= self.capacity()`, or if the iterator ends before index `n` and
+    /// `n > peeked_len()`, since that would leave a gap in the buffer.
+    pub fn peek_nth_or_insert_with(
+        &mut self,
+        n: usize,
+        f: impl FnOnce() -> I::Item,
+    ) -> &mut I::Item {
+        if self.peek_nth(n).is_none() {
+            assert!(
+                n == self.buffer.len(),
+                "peek_nth_or_insert_with: index {} is past the next free slot {}",
+                n,
+                self.buffer.len()
+            );
+            self.buffer.push_back(f());
+        }
+
+        self.buffer.get_mut(n).unwrap()
+    }
+
     /// Peeks at the next item in the iterator without consuming it.
     ///
     /// Equivalent to `peek_nth(0)`.
@@ -229,26 +410,22 @@ where
     ///
     /// # Panics
     /// Panics if the upper bound of the range exceeds `self.capacity()`.
+    /// For inclusive ranges the largest allowed end is `capacity() - 1`, so `0..=S` panics
+    /// with a message pointing at the off-by-one.
     ///
     /// # Returns
-    /// A slice of peeked items within the specified range, possibly shorter if
+    /// A [`PeekRange`] over the peeked items within the specified range, possibly shorter if
     /// the iterator is exhausted.
-    pub fn peek_range<R: RangeBounds<usize>>(
-        &mut self,
-        range: R,
-    ) -> impl Iterator<Item = &<I as Iterator>::Item>
+    pub fn peek_range<R: RangeBounds<usize>>(&mut self, range: R) -> PeekRange<'_, I::Item>
     where
         I: ExactSizeIterator,
     {
         use crate::get_start_end;
+        self.check_inclusive_end(&range);
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
         if start >= end {
-            return self.buffer.range(0..0);
+            return PeekRange::new(self.buffer.as_slices(), 0..0);
         }
 
         for i in start..end {
@@ -264,7 +441,7 @@ where
             end,
             self.buffer.len()
         );
-        self.buffer.range(start..safe_end)
+        PeekRange::new(self.buffer.as_slices(), start.min(safe_end)..safe_end)
     }
 
     /// Mutably peeks a range of items without consuming them.
@@ -277,26 +454,21 @@ where
     ///
     /// # Panics
     /// Panics if the upper bound of the range exceeds `self.capacity()`.
+    /// As with `peek_range`, an inclusive end must be at most `capacity() - 1`.
     ///
     /// # Returns
-    /// A mutable slice of peeked items within the specified range, possibly shorter
+    /// A [`PeekRangeMut`] over the peeked items within the specified range, possibly shorter
     /// if the iterator is exhausted.
-    pub fn peek_range_mut<R: RangeBounds<usize>>(
-        &mut self,
-        range: R,
-    ) -> impl Iterator<Item = &mut <I as Iterator>::Item>
+    pub fn peek_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> PeekRangeMut<'_, I::Item>
     where
         I: ExactSizeIterator,
     {
         use crate::get_start_end;
+        self.check_inclusive_end(&range);
         let (start, end) = get_start_end(range, self.len());
 
-        core::debug_assert!(
-            start < end,
-            "peek_range: start ({start}) must be less than end ({end})"
-        );
         if start >= end {
-            return self.buffer.range_mut(0..0);
+            return PeekRangeMut::new(self.buffer.as_mut_slices(), 0..0);
         }
 
         for i in start..end {
@@ -312,7 +484,71 @@ where
             end,
             self.buffer.len()
         );
-        self.buffer.range_mut(start..safe_end)
+        PeekRangeMut::new(self.buffer.as_mut_slices(), start.min(safe_end)..safe_end)
+    }
+
+    /// Buffers `range` and overwrites each item in it with a fresh value from `f`.
+    ///
+    /// An unbounded end refers to the currently buffered items; if the iterator ends early,
+    /// only the available items are overwritten.
+    ///
+    /// # Panics
+    /// Panics if the end of the range exceeds `self.capacity()`.
+    pub fn fill_peeked_with<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        mut f: impl FnMut() -> I::Item,
+    ) {
+        use crate::get_start_end;
+        let (start, end) = get_start_end(range, self.buffer.len());
+
+        if end > 0 {
+            let _ = self.peek_nth(end - 1);
+        }
+
+        let end = end.min(self.buffer.len());
+        for item in PeekRangeMut::new(self.buffer.as_mut_slices(), start.min(end)..end) {
+            *item = f();
+        }
+    }
+
+    /// Mutably peeks a range of items as a single contiguous slice.
+    ///
+    /// Fills the buffer up to the end of the range, then returns the requested items as one
+    /// `&mut [I::Item]` if they are stored contiguously in the ring buffer. This allows slice
+    /// algorithms such as `sort` or `fill` to operate directly on the lookahead.
+    ///
+    /// An unbounded end refers to the currently buffered items.
+    ///
+    /// # Arguments
+    /// * `range` - A range of indices (e.g., `0..3`, `1..=4`) to peek mutably.
+    ///
+    /// # Panics
+    /// Panics if the upper bound of the range exceeds `self.capacity()`.
+    ///
+    /// # Returns
+    /// `Some(slice)` if the range is contiguous (possibly shorter if the iterator is exhausted),
+    /// or `None` if the range wraps around the end of the ring buffer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use peeknth::sizedpeekn;
+    /// let mut iter = sizedpeekn::<_, 4>([3, 1, 2].into_iter());
+    /// if let Some(slice) = iter.peek_slice_mut(0..3) {
+    ///     slice.sort();
+    /// }
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn peek_slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Option<&mut [I::Item]> {
+        use crate::get_start_end;
+        let (start, end) = get_start_end(range, self.buffer.len());
+
+        if end > 0 {
+            let _ = self.peek_nth(end - 1);
+        }
+
+        let safe_end = end.min(self.buffer.len());
+        self.buffer.slice_mut(start.min(safe_end)..safe_end)
     }
 
     /// Advances the iterator and returns the next value only if it satisfies the predicate.
@@ -323,6 +559,7 @@ where
             Some(matched) if func(&matched) => Some(matched),
             Some(other) => {
                 self.buffer.push_front(other);
+                self.position -= 1;
                 None
             }
             None => None,
@@ -375,7 +612,49 @@ where
             until,
             self.buffer.len()
         );
-        self.buffer.drain(..until);
+        self.buffer.drain_front(until);
+    }
+
+    /// Reverses the order of the buffered items in place.
+    ///
+    /// Only elements that have already been peeked are affected; the underlying
+    /// iterator is left untouched and resumes after the reversed items.
+    #[inline]
+    pub fn reverse_peeked(&mut self) {
+        self.buffer.reverse();
+    }
+
+    /// Rotates the buffered items left by `mid` positions.
+    ///
+    /// Only elements that have already been peeked are affected: the item at index `mid`
+    /// becomes the next one returned, and the first `mid` items move to the end of the
+    /// buffer, ahead of the unread part of the iterator.
+    ///
+    /// # Panics
+    /// Panics if `mid > peeked_len()`.
+    pub fn rotate_peeked_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.buffer.len(),
+            "rotate_peeked_left: mid ({mid}) exceeds peeked length ({})",
+            self.buffer.len()
+        );
+        self.buffer.rotate_left(mid);
+    }
+
+    /// Rotates the buffered items right by `k` positions.
+    ///
+    /// The last `k` buffered items become the next ones returned. The underlying iterator
+    /// is left untouched.
+    ///
+    /// # Panics
+    /// Panics if `k > peeked_len()`.
+    pub fn rotate_peeked_right(&mut self, k: usize) {
+        assert!(
+            k <= self.buffer.len(),
+            "rotate_peeked_right: k ({k}) exceeds peeked length ({})",
+            self.buffer.len()
+        );
+        self.buffer.rotate_right(k);
     }
 
     /// Consumes and yields elements from the iterator while the predicate returns `true`.
@@ -394,6 +673,7 @@ where
                     Some(peeked)
                 } else {
                     self.buffer.push_front(peeked);
+                    self.position -= 1;
                     None
                 }
             } else {
@@ -402,6 +682,27 @@ where
         })
     }
 
+    /// Consumes and yields items while `pred` returns `true`, leaving the first
+    /// non-matching item peekable.
+    ///
+    /// This behaves exactly like `itertools`' `peeking_take_while`, to ease migration: the
+    /// rejected item is not consumed, so the next `peek()` or `next()` returns it.
+    ///
+    /// # Example
+    /// ```
+    /// # use peeknth::sizedpeekn;
+    /// let mut iter = sizedpeekn::<_, 2>("abc123".chars());
+    /// let letters: String = iter.peeking_take_while(|c| c.is_alphabetic()).collect();
+    /// assert_eq!(letters, "abc");
+    /// assert_eq!(iter.peek(), Some(&'1'));
+    /// ```
+    pub fn peeking_take_while(
+        &mut self,
+        mut pred: impl FnMut(&I::Item) -> bool,
+    ) -> impl Iterator<Item = I::Item> {
+        core::iter::from_fn(move || self.next_if(&mut pred))
+    }
+
     /// Peeks ahead while the predicate returns `true`, without consuming any elements.
     ///
     /// This method counts how many consecutive elements satisfy the predicate,
@@ -426,12 +727,70 @@ where
 
     /// Returns the maximum number of items that can be peeked without consuming.
     ///
-    /// This reflects the fixed-size capacity of the internal buffer.
+    /// This reflects the fixed-size capacity of the internal buffer, or `usize::MAX` for a
+    /// buffer that spills to the heap.
     /// Calling `peek_nth(n)` with `n >= capacity()` is undefined behavior (and may panic).
     #[inline(always)]
     pub fn capacity(&self) -> usize {
         self.buffer.capacity()
     }
+
+    /// Returns how many more items can be peeked before the buffer is full.
+    ///
+    /// Equivalent to `capacity() - peeked_len()`. A `peek_nth(k)` that needs to buffer
+    /// new items is safe as long as `k < capacity()`; this tells how many of those slots
+    /// are still free.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.peeked_len()
+    }
+
+    /// Returns `true` if the buffer holds `capacity()` items.
+    ///
+    /// Pushing another item back (e.g. a failed `next_if` on a full buffer) would panic.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::sizedpeekn;
+    /// let mut iter = sizedpeekn::<_, 2>(0..);
+    /// let _ = iter.peek_nth(1);
+    /// assert!(iter.is_full());
+    /// ```
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
+    }
+
+    /// Returns how many items have been yielded by `next()` since creation.
+    ///
+    /// Peeking does not advance the position, and items pushed back by a failed
+    /// `next_if` are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use peeknth::sizedpeekn;
+    /// let mut iter = sizedpeekn::<_, 2>(0..);
+    /// let _ = iter.peek_nth(1);
+    /// let _ = iter.next();
+    /// assert_eq!(iter.absolute_position(), 1);
+    /// ```
+    #[inline]
+    pub fn absolute_position(&self) -> usize {
+        self.position
+    }
+
+    fn check_inclusive_end<R: RangeBounds<usize>>(&self, range: &R) {
+        let cap = self.capacity();
+        if let core::ops::Bound::Included(&end) = range.end_bound() {
+            assert!(
+                end < cap,
+                "peek_range: inclusive end {end} is out of bounds; the max inclusive end is {} \
+                 for capacity {cap} (use `..={}` or `..{cap}`)",
+                cap.saturating_sub(1),
+                cap.saturating_sub(1)
+            );
+        }
+    }
 }
 
 /// Creates a new `SizedPeekN<I, S>` from the given iterator.
@@ -440,7 +799,6 @@ where
 pub fn sizedpeekn<I, const S: usize>(iter: I) -> SizedPeekN<I, S>
 where
     I: Iterator,
-    I::Item: Copy,
 {
     SizedPeekN::new(iter)
 }
@@ -0,0 +1,64 @@
+/// A chain of two fused iterators that preserves `ExactSizeIterator`.
+///
+/// `core::iter::Chain` does not implement `ExactSizeIterator` because the combined
+/// length could overflow; peek buffers are bounded by memory, so that cannot happen here.
+#[derive(Clone, Debug)]
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Chain<A, B> {
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        Chain { a, b }
+    }
+}
+
+impl<A, B> Iterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.a.next().or_else(|| self.b.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lo.saturating_add(b_lo), hi)
+    }
+}
+
+impl<A, B> DoubleEndedIterator for Chain<A, B>
+where
+    A: DoubleEndedIterator,
+    B: DoubleEndedIterator<Item = A::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.b.next_back().or_else(|| self.a.next_back())
+    }
+}
+
+impl<A, B> ExactSizeIterator for Chain<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator<Item = A::Item>,
+{
+}
+
+impl<A, B> core::iter::FusedIterator for Chain<A, B>
+where
+    A: core::iter::FusedIterator,
+    B: core::iter::FusedIterator<Item = A::Item>,
+{
+}
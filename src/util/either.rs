@@ -15,6 +15,13 @@ where
             Either::Chain(i) => i.next(),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Single(i) => i.size_hint(),
+            Either::Chain(i) => i.size_hint(),
+        }
+    }
 }
 impl<S, C> DoubleEndedIterator for Either<S, C>
 where
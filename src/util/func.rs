@@ -13,3 +13,12 @@ pub fn get_start_end(range: impl core::ops::RangeBounds<usize>, len: usize) -> (
     };
     (start, end)
 }
+
+/// Adds `extra` buffered items to an inner iterator's `size_hint`.
+pub fn add_size_hint(hint: (usize, Option<usize>), extra: usize) -> (usize, Option<usize>) {
+    let (lo, hi) = hint;
+    (
+        lo.saturating_add(extra),
+        hi.and_then(|hi| hi.checked_add(extra)),
+    )
+}
@@ -1,10 +1,17 @@
+#[cfg(feature = "peekdn")]
+mod chain;
+#[cfg(feature = "peekdn")]
+pub(crate) use chain::Chain;
+
 #[cfg(any(feature = "peekdn", feature = "peekn"))]
 mod either;
 #[cfg(any(feature = "peekdn", feature = "peekn"))]
 pub(crate) use either::Either;
 
-#[cfg(any(feature = "peekde", feature = "peekn"))]
+#[cfg(any(feature = "peekde", feature = "peekn", feature = "peekdn"))]
 mod func;
+#[cfg(any(feature = "peekde", feature = "peekn", feature = "peekdn"))]
+pub(crate) use func::add_size_hint;
 #[cfg(any(feature = "peekde", feature = "peekn"))]
 pub(crate) use func::get_start_end;
 
@@ -16,4 +23,4 @@ pub(crate) use peeksource::PeekSource;
 #[cfg(any(feature = "peekdn", feature = "peekn"))]
 mod ringbuffer;
 #[cfg(any(feature = "peekdn", feature = "peekn"))]
-pub(crate) use ringbuffer::Buffer;
+pub use ringbuffer::Buffer;
@@ -3,40 +3,64 @@ use core::{mem::MaybeUninit, ops::RangeBounds, slice};
 #[cfg(feature = "alloc")]
 use alloc::collections::VecDeque;
 
+use crate::error::PeekError;
 use crate::util::Either;
 
-pub struct Buffer<T: Copy, const N: usize> {
+/// A fixed-capacity ring buffer stored inline, holding at most `N` items.
+///
+/// This is the default [`PeekBuffer`](crate::PeekBuffer) of
+/// [`SizedPeekN`](crate::SizedPeekN) and [`SizedPeekDN`](crate::SizedPeekDN).
+///
+/// Only the `len` slots starting at `head` are initialized; they are dropped when
+/// removed, cleared, or when the buffer itself is dropped.
+pub struct Buffer<T, const N: usize> {
     buffer: [MaybeUninit<T>; N],
     head: usize,
     tail: usize,
     len: usize,
 }
 
-impl<T: Copy + PartialEq, const N: usize> PartialEq for Buffer<T, N> {
+impl<T: PartialEq, const N: usize> PartialEq for Buffer<T, N> {
     fn eq(&self, other: &Self) -> bool {
         self.len == other.len && (0..self.len).all(|i| self.get(i) == other.get(i))
     }
 }
 
-impl<T: Copy, const N: usize> Default for Buffer<T, N> {
+/// Hashes the length and the live elements in order, like a slice.
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for Buffer<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for item in self.range(..) {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for Buffer<T, N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Copy + Eq, const N: usize> Eq for Buffer<T, N> {}
+impl<T: Eq, const N: usize> Eq for Buffer<T, N> {}
 
-impl<T: Copy, const N: usize> Clone for Buffer<T, N> {
+impl<T: Clone, const N: usize> Clone for Buffer<T, N> {
     fn clone(&self) -> Self {
         let mut new = Buffer::new();
         for i in 0..self.len {
-            new.push_back(*self.get(i).unwrap());
+            new.push_back(self.get(i).unwrap().clone());
         }
         new
     }
 }
 
-impl<T: Copy + core::fmt::Debug, const N: usize> core::fmt::Debug for Buffer<T, N> {
+impl<T, const N: usize> Drop for Buffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Buffer<T, N> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Buffer[")?;
         for i in 0..self.len {
@@ -49,21 +73,58 @@ impl<T: Copy + core::fmt::Debug, const N: usize> core::fmt::Debug for Buffer<T,
     }
 }
 
-impl<T: Copy, const N: usize> FromIterator<T> for Buffer<T, N> {
+impl<T, const N: usize> FromIterator<T> for Buffer<T, N> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut buffer = Buffer::new();
-        for item in iter {
-            if buffer.len() >= N {
-                panic!("Buffer overflow in FromIterator: max size is {}", N);
+        match Buffer::try_from_iter(iter) {
+            Ok(buffer) => buffer,
+            Err(_) => panic!("Buffer overflow in FromIterator: max size is {}", N),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Buffer<T, N> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.range(..))
+    }
+}
+
+/// Deserializes a sequence of at most `N` items, rejecting longer ones.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Buffer<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BufferVisitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de>
+            for BufferVisitor<T, N>
+        {
+            type Value = Buffer<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut buffer = Buffer::new();
+                while let Some(item) = seq.next_element()? {
+                    if buffer.len() == N {
+                        return Err(serde::de::Error::invalid_length(N + 1, &self));
+                    }
+                    buffer.push_back(item);
+                }
+                Ok(buffer)
             }
-            buffer.push_back(item);
         }
-        buffer
+
+        deserializer.deserialize_seq(BufferVisitor(core::marker::PhantomData))
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<T: Copy, const N: usize> From<Buffer<T, N>> for VecDeque<T> {
+impl<T, const N: usize> From<Buffer<T, N>> for VecDeque<T> {
     fn from(mut buf: Buffer<T, N>) -> Self {
         let mut deque = VecDeque::with_capacity(buf.len());
         while let Some(val) = buf.pop_front() {
@@ -73,17 +134,22 @@ impl<T: Copy, const N: usize> From<Buffer<T, N>> for VecDeque<T> {
     }
 }
 
-impl<T: Copy, const N: usize> Buffer<T, N> {
+impl<T, const N: usize> Buffer<T, N> {
+    /// Creates an empty buffer.
     #[inline]
     pub fn new() -> Self {
         Buffer {
-            buffer: [MaybeUninit::uninit(); N],
+            buffer: [const { MaybeUninit::uninit() }; N],
             head: 0,
             tail: 0,
             len: 0,
         }
     }
 
+    /// Prepends an item at the front.
+    ///
+    /// # Panics
+    /// Panics if the buffer already holds `N` items.
     pub fn push_front(&mut self, value: T) {
         if self.len == N {
             panic!("buffer full");
@@ -94,6 +160,10 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         self.len += 1;
     }
 
+    /// Appends an item at the back.
+    ///
+    /// # Panics
+    /// Panics if the buffer already holds `N` items.
     pub fn push_back(&mut self, value: T) {
         if self.len == N {
             panic!("buffer full");
@@ -104,28 +174,31 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         self.len += 1;
     }
 
+    /// Removes and returns the first item.
     pub fn pop_front(&mut self) -> Option<T> {
         if self.len == 0 {
             return None;
         }
 
-        let value = unsafe { self.buffer[self.head].assume_init() };
+        let value = unsafe { self.buffer[self.head].assume_init_read() };
         self.head = (self.head + 1) % N;
         self.len -= 1;
         Some(value)
     }
 
+    /// Removes and returns the last item.
     pub fn pop_back(&mut self) -> Option<T> {
         if self.len == 0 {
             return None;
         }
 
         self.tail = (self.tail + N - 1) % N;
-        let value = unsafe { self.buffer[self.tail].assume_init() };
+        let value = unsafe { self.buffer[self.tail].assume_init_read() };
         self.len -= 1;
         Some(value)
     }
 
+    /// Returns the item at `index`, or `None` if it is out of bounds.
     pub fn get(&self, index: usize) -> Option<&T> {
         if index >= self.len {
             return None;
@@ -134,6 +207,7 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         Some(unsafe { &*self.buffer[pos].as_ptr() })
     }
 
+    /// Returns the item at `index` mutably, or `None` if it is out of bounds.
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
         if index >= self.len {
             return None;
@@ -142,10 +216,14 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         Some(unsafe { &mut *self.buffer[pos].as_mut_ptr() })
     }
 
+    /// Returns an iterator over the items in `range`.
+    ///
+    /// # Panics
+    /// Panics if the range ends past [`len`](Self::len).
     pub fn range<'a>(
         &'a self,
         range: impl RangeBounds<usize>,
-    ) -> impl DoubleEndedIterator<Item = &'a T>
+    ) -> impl DoubleEndedIterator<Item = &'a T> + Clone
     where
         T: 'a,
     {
@@ -163,20 +241,15 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         }
 
         let first_index = (self.head + start) % N;
-        let last_index = (self.head + end) % N;
 
-        if first_index < last_index || (first_index == last_index && count > 0) {
+        if first_index + count <= N {
             let slice = unsafe {
                 let ptr = self.buffer[first_index].as_ptr();
                 slice::from_raw_parts(ptr, count)
             };
             Either::Single(slice.iter())
         } else {
-            let first_len = if first_index <= last_index {
-                end - start
-            } else {
-                N - first_index
-            };
+            let first_len = N - first_index;
 
             let second_len = count - first_len;
 
@@ -189,6 +262,7 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         }
     }
 
+    /// Mutable version of [`range`](Self::range).
     pub fn range_mut<'a>(
         &'a mut self,
         range: impl RangeBounds<usize>,
@@ -210,20 +284,15 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         }
 
         let first_index = (self.head + start) % N;
-        let last_index = (self.head + end) % N;
 
-        if first_index < last_index || (first_index == last_index && count > 0) {
+        if first_index + count <= N {
             let slice = unsafe {
                 let ptr = self.buffer[first_index].as_mut_ptr();
                 slice::from_raw_parts_mut(ptr, count)
             };
             Either::Single(slice.iter_mut())
         } else {
-            let first_len = if first_index <= last_index {
-                end - start
-            } else {
-                N - first_index
-            };
+            let first_len = N - first_index;
 
             let second_len = count - first_len;
 
@@ -238,18 +307,71 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
         }
     }
 
+    /// Returns the items in `range` as one slice, or `None` if they wrap around the end of
+    /// the ring.
+    ///
+    /// # Panics
+    /// Panics if the range ends past [`len`](Self::len).
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut [T]> {
+        use crate::get_start_end;
+
+        let (start, end) = get_start_end(range, self.len);
+
+        assert!(end <= self.len, "range out of bounds");
+
+        let count = end.saturating_sub(start);
+
+        if count == 0 {
+            return Some(&mut []);
+        }
+
+        let first_index = (self.head + start) % N;
+
+        if first_index + count > N {
+            return None;
+        }
+
+        Some(unsafe { slice::from_raw_parts_mut(self.buffer[first_index].as_mut_ptr(), count) })
+    }
+
+    /// Returns the number of items held.
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.len
     }
 
-    #[inline]
+    /// Returns `true` if the buffer holds no items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Drops all items.
     pub fn clear(&mut self) {
+        let len = self.len;
         self.len = 0;
+        self.drop_slots(self.head, len);
         self.head = 0;
         self.tail = 0;
     }
 
+    /// Drops `count` initialized items in the slots starting at physical index `first`.
+    ///
+    /// The slots must already lie outside the live range.
+    fn drop_slots(&mut self, first: usize, count: usize) {
+        if !core::mem::needs_drop::<T>() {
+            return;
+        }
+        for i in 0..count {
+            let pos = (first + i) % N;
+            unsafe { self.buffer[pos].assume_init_drop() };
+        }
+    }
+
+    /// Drops the items in `range`, moving the later items forward.
+    ///
+    /// # Panics
+    /// Panics if the range ends past [`len`](Self::len).
     pub fn drain(&mut self, range: impl RangeBounds<usize>) {
         use crate::get_start_end;
 
@@ -263,32 +385,138 @@ impl<T: Copy, const N: usize> Buffer<T, N> {
             return;
         }
 
+        // Update the bookkeeping before dropping anything, so a panicking `Drop` can only
+        // leak the remaining removed items instead of having `clear()` drop them again.
         if start == 0 {
+            let removed_head = self.head;
             self.head = (self.head + count) % N;
-        } else if end == self.len {
-            self.tail = (self.tail + N - count) % N;
+            self.len -= count;
+            self.drop_slots(removed_head, count);
         } else {
+            // Swapping each survivor into place moves the removed items behind them.
             for i in 0..self.len - end {
                 let from = (self.head + end + i) % N;
                 let to = (self.head + start + i) % N;
-
-                let value = unsafe { self.buffer[from].assume_init() };
-                self.buffer[to].write(value);
+                self.buffer.swap(from, to);
             }
 
             self.tail = (self.tail + N - count) % N;
+            self.len -= count;
+            self.drop_slots(self.tail, count);
+        }
+    }
+
+    /// Reverses the order of the items in place.
+    pub fn reverse(&mut self) {
+        if self.len < 2 {
+            return;
         }
 
-        self.len -= count;
+        let (mut i, mut j) = (0, self.len - 1);
+        while i < j {
+            let a = (self.head + i) % N;
+            let b = (self.head + j) % N;
+            self.buffer.swap(a, b);
+            i += 1;
+            j -= 1;
+        }
     }
 
-    #[inline]
-    pub fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        iter.into_iter().collect()
+    /// Rotates the live elements so that the one at `mid` becomes the first.
+    ///
+    /// A full ring only moves `head`; otherwise `mid` elements are moved from the front end
+    /// to the back end one by one.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than [`len`](Self::len).
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "rotate_left: mid out of bounds");
+        if self.len < 2 {
+            return;
+        }
+
+        if self.len == N {
+            self.head = (self.head + mid) % N;
+            self.tail = self.head;
+            return;
+        }
+
+        for _ in 0..mid {
+            let value = unsafe { self.buffer[self.head].assume_init_read() };
+            self.buffer[self.tail].write(value);
+            self.head = (self.head + 1) % N;
+            self.tail = (self.tail + 1) % N;
+        }
     }
 
+    /// Rotates the live elements so that the last `k` become the first.
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than [`len`](Self::len).
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "rotate_right: k out of bounds");
+        if self.len < 2 {
+            return;
+        }
+
+        if self.len == N {
+            self.head = (self.head + N - k) % N;
+            self.tail = self.head;
+            return;
+        }
+
+        for _ in 0..k {
+            self.tail = (self.tail + N - 1) % N;
+            self.head = (self.head + N - 1) % N;
+            let value = unsafe { self.buffer[self.tail].assume_init_read() };
+            self.buffer[self.head].write(value);
+        }
+    }
+
+    /// Collects `iter` into a buffer, failing with [`PeekError::BufferOverflow`] if it yields
+    /// more than `N` items.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, PeekError> {
+        let mut buffer = Buffer::new();
+        for item in iter {
+            if buffer.len() >= N {
+                return Err(PeekError::BufferOverflow { capacity: N });
+            }
+            buffer.push_back(item);
+        }
+        Ok(buffer)
+    }
+
+    /// Returns `N`, the maximum number of items the buffer can hold.
     #[inline(always)]
     pub fn capacity(&self) -> usize {
         N
     }
+
+    /// Returns the live elements as two slices: from `head` to the end of the array, then
+    /// the wrapped-around part.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.len.min(N - self.head);
+        let ptr = self.buffer.as_ptr() as *const T;
+        // SAFETY: the `len` slots starting at `head` (wrapping at `N`) are initialized, and
+        // the two ranges `head..head + first_len` and `0..len - first_len` cover exactly those.
+        unsafe {
+            (
+                slice::from_raw_parts(ptr.add(self.head), first_len),
+                slice::from_raw_parts(ptr, self.len - first_len),
+            )
+        }
+    }
+
+    /// Mutable version of [`as_slices`](Self::as_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let first_len = self.len.min(N - self.head);
+        let ptr = self.buffer.as_mut_ptr() as *mut T;
+        // SAFETY: as in `as_slices`; the two ranges do not overlap because `len <= N`.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(self.head), first_len),
+                slice::from_raw_parts_mut(ptr, self.len - first_len),
+            )
+        }
+    }
 }
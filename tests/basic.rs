@@ -377,4 +377,2474 @@ mod tests {
         let back: Vec<_> = iter.while_next_back(|&x| x >= 8).collect();
         assert_eq!(back, vec![9, 8]);
     }
+
+    #[test]
+    fn test_reverse_peeked() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(2);
+        iter.reverse_peeked();
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn test_sized_reverse_peeked() {
+        let mut iter = sizedpeekn::<_, 4>(0..);
+        let _ = iter.next_if(|_| false);
+        let _ = iter.peek_nth(2);
+        iter.reverse_peeked();
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn test_sized_peek_slice_mut() {
+        let mut iter = sizedpeekn::<_, 4>([3, 1, 2, 0].into_iter());
+        let slice = iter.peek_slice_mut(0..3).unwrap();
+        slice.sort();
+        assert_eq!(slice, &[1, 2, 3]);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(0));
+    }
+
+    #[test]
+    fn test_sized_peek_slice_mut_wrapped() {
+        let mut iter = sizedpeekn::<_, 4>(0..);
+        let _ = iter.next_if(|_| false); // 0 is pushed to the end of the ring
+        assert!(iter.peek_slice_mut(0..3).is_none());
+        assert_eq!(iter.peek_slice_mut(1..3), Some(&mut [1, 2][..]));
+    }
+
+    #[test]
+    fn test_peek_find_ref() {
+        let mut iter = peekn([1, 3, 4, 5].into_iter());
+        assert_eq!(iter.peek_find_ref(|&x| x % 2 == 0), Some((2, &4)));
+        assert_eq!(iter.peek_find_ref(|&x| x > 10), None);
+        assert_eq!(iter.next(), Some(1));
+    }
+
+    #[test]
+    fn test_next_if_either() {
+        let mut iter = peekdn(0..=9);
+        assert_eq!(
+            iter.next_if_either(|&x| x < 2, |&x| x > 7),
+            Some((End::Front, 0))
+        );
+        assert_eq!(
+            iter.next_if_either(|&x| x < 2, |&x| x > 7),
+            Some((End::Front, 1))
+        );
+        assert_eq!(
+            iter.next_if_either(|&x| x < 2, |&x| x > 7),
+            Some((End::Back, 9))
+        );
+        assert_eq!(
+            iter.next_if_either(|&x| x < 2, |&x| x > 7),
+            Some((End::Back, 8))
+        );
+        assert_eq!(iter.next_if_either(|&x| x < 2, |&x| x > 7), None);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(7));
+
+        let mut iter = peekdn(0..=9);
+        iter.peek_front_nth(3);
+        iter.peek_back_nth(3);
+        let taken: Vec<_> =
+            core::iter::from_fn(|| iter.next_if_either(|&x| x < 2, |&x| x > 7)).collect();
+        assert_eq!(
+            taken,
+            vec![
+                (End::Front, 0),
+                (End::Front, 1),
+                (End::Back, 9),
+                (End::Back, 8)
+            ]
+        );
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_peek_matches_peek_nth_zero() {
+        let mut iter = peekn(0..3);
+        let _ = iter.peek_nth(2);
+        assert_eq!(iter.peek(), Some(&0));
+        if let Some(x) = iter.peek_mut() {
+            *x = 10;
+        }
+        assert_eq!(iter.peek_nth(0), Some(&10));
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.peek_mut(), None);
+    }
+
+    #[test]
+    fn test_peek_range_checked() {
+        let mut iter = peekn(0..5);
+        let full: Vec<_> = iter
+            .peek_range_checked(1..4)
+            .ok()
+            .unwrap()
+            .copied()
+            .collect();
+        assert_eq!(full, vec![1, 2, 3]);
+
+        let (partial, count) = iter.peek_range_checked(2..10).err().unwrap();
+        assert_eq!(partial.copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(count, 3);
+
+        let (partial, count) = iter.peek_range_checked(7..9).err().unwrap();
+        assert_eq!(partial.count(), 0);
+        assert_eq!(count, 0);
+        assert_eq!(iter.next(), Some(0));
+    }
+
+    #[test]
+    fn test_peek_nth_or_insert_with() {
+        let mut iter = peekn(0..2);
+        *iter.peek_nth_or_insert_with(0, || 99) += 10;
+        assert_eq!(*iter.peek_nth_or_insert_with(2, || 99), 99);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 1, 99]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_peek_nth_or_insert_with_gap() {
+        let mut iter = peekn(0..2);
+        let _ = iter.peek_nth_or_insert_with(3, || 0);
+    }
+
+    #[test]
+    fn test_sized_peek_nth_or_insert_with() {
+        let mut iter = sizedpeekn::<_, 3>(0..1);
+        assert_eq!(*iter.peek_nth_or_insert_with(1, || 7), 7);
+        assert_eq!(iter.peeked_len(), 2);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_take_while_sum() {
+        let mut iter = peekn([3, 3, 3, 3].into_iter());
+        assert_eq!(iter.take_while_sum(7), vec![3, 3]);
+        assert_eq!(iter.peeked_len(), 1);
+        assert_eq!(iter.take_while_sum(2), Vec::<i32>::new());
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.take_while_sum(100), vec![3]);
+    }
+
+    #[test]
+    fn test_de_while_peek_front_and_back() {
+        let mut iter = peekablede(0..10);
+        assert_eq!(iter.while_peek_front(|&x| x < 3), 1);
+        assert_eq!(iter.while_peek_front(|&x| x > 3), 0);
+        assert_eq!(iter.while_peek_back(|&x| x == 9), 1);
+        assert_eq!(iter.while_peek_back(|&x| x == 0), 0);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(9));
+
+        let mut empty = peekablede(0..0);
+        assert_eq!(empty.while_peek_front(|_| true), 0);
+        assert_eq!(empty.while_peek_back(|_| true), 0);
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let mut a = peekn([1, 2, 3, 9].into_iter());
+        let mut b = peekn([1, 2, 3, 8].into_iter());
+        assert_eq!(a.common_prefix_len(&mut b), 3);
+        assert_eq!(a.next(), Some(1));
+        assert_eq!(b.next(), Some(1));
+
+        let mut short = peekn([2, 3].into_iter());
+        assert_eq!(a.common_prefix_len(&mut short), 2);
+        assert_eq!(short.next(), Some(2));
+    }
+
+    #[test]
+    fn test_peek_front_range_exact_size_cross_buffer() {
+        let mut iter = peekdn(0..5);
+        let _ = iter.peek_back_nth(1); // back buffer holds [4, 3]
+
+        let range = iter.peek_front_range(1..5);
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        let range = iter.peek_front_range(4..5);
+        assert_eq!(range.len(), 1);
+        assert_eq!(range.cloned().collect::<Vec<_>>(), vec![4]);
+
+        let back = iter.peek_back_range(1..4);
+        assert_eq!(back.len(), 3);
+        assert_eq!(back.cloned().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_peek_mut_guard() {
+        let mut iter = peekn(0..3);
+        {
+            let mut guard = iter.peek_mut_guard().unwrap();
+            assert_eq!(*guard, 0);
+            *guard = 42;
+        }
+        assert_eq!(iter.peek(), Some(&42));
+        assert_eq!(iter.next(), Some(42));
+
+        let _ = iter.by_ref().count();
+        assert!(iter.peek_mut_guard().is_none());
+    }
+
+    #[test]
+    fn test_peek_mut_guard_pop() {
+        let mut iter = peekn(0..5);
+        let _ = iter.peek_nth(2);
+        let mut guard = iter.peek_mut_guard().unwrap();
+        *guard += 10;
+        assert_eq!(guard.pop(), 10);
+        assert_eq!(iter.peeked_len(), 2);
+        assert_eq!(iter.next(), Some(1));
+
+        let guard = iter.peek_mut_guard().unwrap();
+        assert_eq!(guard.pop(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_peek_range_while() {
+        let mut iter = peekn(0..10);
+        let values: Vec<_> = iter.peek_range_while(2, |&x| x < 7).cloned().collect();
+        assert_eq!(values, vec![2, 3, 4, 5, 6]);
+        assert_eq!(iter.peek_range_while(20, |_| true).count(), 0);
+        assert_eq!(iter.next(), Some(0));
+    }
+
+    #[test]
+    fn test_peek_and_next_multi_byte() {
+        let bytes: Vec<u8> = vec![0x12, 0x34, 0xde, 0xad, 0xbe, 0xef, 0x01];
+        let mut iter = peekn(bytes.into_iter());
+        assert_eq!(iter.peek_u16_be(), Some(0x1234));
+        assert_eq!(iter.next_u16_be(), Some(0x1234));
+        assert_eq!(iter.peek_u32_be(), Some(0xdead_beef));
+        assert_eq!(iter.next_u32_be(), Some(0xdead_beef));
+        assert_eq!(iter.next_u16_be(), None); // only one byte left
+        assert_eq!(iter.next(), Some(0x01));
+    }
+
+    #[test]
+    fn test_rotate_front_to_back() {
+        let mut iter = peekdn(0..5);
+        let _ = iter.peek_front_nth(4);
+        iter.rotate_front_to_back(2);
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), Some(0));
+
+        let mut iter = peekdn(0..10);
+        let _ = iter.peek_front_nth(3);
+        let _ = iter.peek_back();
+        iter.rotate_front_to_back(2);
+        let rest: Vec<_> = iter.collect();
+        assert_eq!(rest, vec![0, 1, 4, 5, 6, 7, 8, 2, 3, 9]);
+    }
+
+    #[test]
+    fn test_sized_clone_wrapped_buffer() {
+        let mut iter = sizedpeekn::<_, 3>(0..10);
+        for _ in 0..4 {
+            let _ = iter.peek_nth(1);
+            let _ = iter.next();
+            let _ = iter.next_if(|_| false); // push_front moves the ring head
+        }
+        let _ = iter.peek_nth(2);
+
+        let mut cloned = iter.clone();
+        assert!(cloned == iter);
+        for n in 0..3 {
+            assert_eq!(cloned.peek_nth(n), iter.peek_nth(n));
+        }
+        assert_eq!(
+            cloned.by_ref().collect::<Vec<_>>(),
+            iter.by_ref().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_sized_eq_ignores_ring_layout() {
+        let mut wrapped = sizedpeekn::<_, 3>(1..5);
+        let _ = wrapped.next_if(|_| false);
+        let _ = wrapped.peek_nth(1);
+
+        let mut flat = sizedpeekn::<_, 3>(1..5);
+        let _ = flat.peek_nth(1);
+
+        assert!(wrapped == flat);
+        assert!(wrapped.clone() == flat.clone());
+
+        let mut dn = sizedpeekdn::<_, 2, 2>(0..6);
+        let _ = dn.next_if(|_| false);
+        let _ = dn.next_back_if(|_| false);
+        let _ = dn.peek_front_nth(1);
+        let cloned = dn.clone();
+        assert!(cloned == dn);
+        assert_eq!(cloned.collect::<Vec<_>>(), dn.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_peek_while_with_prev() {
+        let unescaped = |prev: Option<&char>, c: &char| *c != '"' || prev == Some(&'\\');
+
+        let mut iter = peekn(r#"ab\"cd"rest"#.chars());
+        assert_eq!(iter.peek_while_with_prev(unescaped), 6);
+        assert_eq!(iter.peeked_len(), 7);
+        assert_eq!(iter.next(), Some('a'));
+
+        let mut iter = peekn(r#""quoted"#.chars());
+        assert_eq!(iter.peek_while_with_prev(unescaped), 0);
+
+        let mut iter = peekn(r#"no\"quote"#.chars());
+        assert_eq!(iter.peek_while_with_prev(unescaped), 9);
+
+        let mut iter = peekn(core::iter::empty::<char>());
+        assert_eq!(iter.peek_while_with_prev(unescaped), 0);
+    }
+
+    #[test]
+    fn test_peek_back_nth_bounded() {
+        let mut iter = peekdn(0..10);
+        assert_eq!(iter.peek_back_nth_bounded(3, 10), Some(&6));
+        assert_eq!(iter.peek_back_nth_bounded(3, 0), Some(&6));
+
+        let mut iter = peekdn(0..10);
+        assert_eq!(iter.peek_back_nth_bounded(4, 2), None);
+        assert_eq!(iter.peek_back_nth_bounded(4, 2), None);
+        assert_eq!(iter.peek_back_nth_bounded(4, 1), Some(&5));
+
+        let mut iter = peekdn(0..3);
+        let _ = iter.peek_front_nth(1);
+        assert_eq!(iter.peek_back_nth_bounded(2, 5), Some(&0));
+        assert_eq!(iter.peek_back_nth_bounded(3, 5), None);
+
+        let mut iter = peekdn(0..u64::MAX);
+        assert_eq!(iter.peek_back_nth_bounded(1_000, 10), None);
+        assert_eq!(iter.peek_back_nth_bounded(0, 0), Some(&(u64::MAX - 1)));
+    }
+
+    #[test]
+    fn test_front_entry() {
+        let mut iter = peekn(0..3);
+        assert!(iter.front_entry(1).is_occupied());
+        *iter.front_entry(1).and_modify(|x| *x += 10).or_insert(99) += 1;
+        assert_eq!(iter.peek_nth(1), Some(&12));
+
+        match iter.front_entry(4) {
+            PeekEntry::Vacant(entry) => {
+                assert_eq!(entry.index(), 4);
+                assert!(!entry.is_next_slot());
+            }
+            PeekEntry::Occupied(_) => panic!("slot 4 should be vacant"),
+        }
+
+        assert_eq!(*iter.front_entry(3).and_modify(|x| *x = 0).or_insert(7), 7);
+        assert_eq!(iter.peeked_len(), 4);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 12, 2, 7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_front_entry_insert_gap() {
+        let mut iter = peekn(0..2);
+        iter.front_entry(5).or_insert(0);
+    }
+
+    #[test]
+    fn test_sized_while_peek_max() {
+        let mut iter = sizedpeekdn::<_, 5, 5>(0..20);
+        assert_eq!(iter.while_peek_front_max(2, |_| true), 2);
+        assert_eq!(iter.front_peeked_len(), 2);
+        assert_eq!(iter.while_peek_front_max(10, |_| true), 5);
+        assert_eq!(iter.while_peek_front_max(4, |&x| x < 3), 3);
+
+        assert_eq!(iter.while_peek_back_max(2, |_| true), 2);
+        assert_eq!(iter.back_peeked_len(), 2);
+        assert_eq!(iter.while_peek_back_max(10, |&x| x > 0), 5);
+        assert_eq!(iter.while_peek_back_max(0, |_| true), 0);
+
+        let mut short = sizedpeekdn::<_, 5, 5>(0..1);
+        assert_eq!(short.while_peek_front_max(3, |_| true), 1);
+    }
+
+    #[test]
+    fn test_remaining_capacity() {
+        let mut iter = sizedpeekn::<_, 4>(0..10);
+        assert_eq!(iter.remaining_capacity(), 4);
+        let _ = iter.peek_nth(2);
+        assert_eq!(iter.remaining_capacity(), 1);
+        let _ = iter.next();
+        assert_eq!(iter.remaining_capacity(), 2);
+        let _ = iter.peek_nth(3);
+        assert_eq!(iter.remaining_capacity(), 0);
+
+        let mut iter = sizedpeekdn::<_, 3, 2>(0..10);
+        assert_eq!(iter.front_remaining_capacity(), 3);
+        assert_eq!(iter.back_remaining_capacity(), 2);
+        let _ = iter.peek_front_nth(1);
+        let _ = iter.peek_back_nth(1);
+        assert_eq!(iter.front_remaining_capacity(), 1);
+        assert_eq!(iter.back_remaining_capacity(), 0);
+        let _ = iter.next_back();
+        assert_eq!(iter.back_remaining_capacity(), 1);
+    }
+
+    #[test]
+    fn test_front_peek_spilled_to_back() {
+        let mut iter = sizedpeekdn::<_, 2, 4>(0..4);
+        assert!(!iter.front_peek_spilled_to_back());
+
+        assert_eq!(iter.peek_back_nth(3), Some(&0));
+        assert_eq!(iter.back_peeked_len(), 4);
+
+        // All items live in the back buffer, but front capacity stays untouched.
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert!(iter.front_peek_spilled_to_back());
+        assert_eq!(iter.front_peeked_len(), 0);
+        assert_eq!(iter.front_remaining_capacity(), 2);
+
+        *iter.peek_front_nth_mut(0).unwrap() = 10;
+        assert!(iter.front_peek_spilled_to_back());
+        assert_eq!(iter.peek_back_nth(3), Some(&10));
+
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert_eq!(iter.peek_front_nth(4), None);
+        assert!(!iter.front_peek_spilled_to_back());
+
+        let mut iter = sizedpeekdn::<_, 2, 2>(0..4);
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert!(!iter.front_peek_spilled_to_back());
+    }
+
+    #[test]
+    fn test_run_length_encode() {
+        let runs: Vec<_> = peekn(vec![1, 1, 2, 3, 3, 3].into_iter())
+            .run_length_encode()
+            .collect();
+        assert_eq!(runs, vec![(1, 2), (2, 1), (3, 3)]);
+
+        let mut iter = peekn("aab".chars());
+        let _ = iter.peek_nth(2);
+        assert_eq!(
+            iter.run_length_encode().collect::<Vec<_>>(),
+            vec![('a', 2), ('b', 1)]
+        );
+
+        assert_eq!(
+            peekn(core::iter::empty::<u8>()).run_length_encode().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_sized_peek_range_inclusive_max() {
+        let mut iter = sizedpeekn::<_, 4>(0..10);
+        assert_eq!(
+            iter.peek_range(0..=3).copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(iter.peek_range_mut(2..=3).count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "max inclusive end is 3 for capacity 4")]
+    fn test_sized_peek_range_inclusive_overflow() {
+        let mut iter = sizedpeekn::<_, 4>(0..10);
+        let _ = iter.peek_range(0..=4).count();
+    }
+
+    #[test]
+    fn test_peek_nth_if() {
+        let mut iter = peekn(10..13);
+        assert_eq!(iter.peek_nth_if(1, |&x| Some(x * 2)), Some(22));
+        assert_eq!(iter.peek_nth_if(1, |&x| (x > 20).then_some(x)), None);
+        assert_eq!(iter.peek_nth_if(5, |&x| Some(x)), None);
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn test_peekablede_model_matrix() {
+        use std::collections::VecDeque;
+
+        // 0 = peek_front, 1 = peek_back, 2 = next, 3 = next_back,
+        // 4 = rejected next_if, 5 = rejected next_back_if
+        fn run(len: i32, ops: &[u8]) {
+            let mut iter = peekablede(0..len);
+            let mut model: VecDeque<i32> = (0..len).collect();
+            for (step, op) in ops.iter().enumerate() {
+                let (got, want) = match op {
+                    0 => (iter.peek_front().copied(), model.front().copied()),
+                    1 => (iter.peek_back().copied(), model.back().copied()),
+                    2 => (iter.next(), model.pop_front()),
+                    3 => (iter.next_back(), model.pop_back()),
+                    4 => (iter.next_if(|_| false), None),
+                    _ => (iter.next_back_if(|_| false), None),
+                };
+                assert_eq!(got, want, "len {len}, ops {ops:?}, step {step}");
+                assert_eq!(
+                    iter.len(),
+                    model.len(),
+                    "len {len}, ops {ops:?}, step {step}"
+                );
+            }
+            let rest: Vec<_> = iter.collect();
+            assert_eq!(rest, Vec::from(model), "len {len}, ops {ops:?}");
+        }
+
+        fn each(len: i32, ops: &mut Vec<u8>, depth: usize) {
+            run(len, ops);
+            if depth == 0 {
+                return;
+            }
+            for op in 0..6 {
+                ops.push(op);
+                each(len, ops, depth - 1);
+                ops.pop();
+            }
+        }
+
+        for len in 1..=3 {
+            each(len, &mut Vec::new(), len as usize + 3);
+        }
+    }
+
+    #[test]
+    fn test_peek_map() {
+        let mut iter = peekn(1..);
+        let squares: Vec<i32> = iter.peek_map(3, |x| x * x);
+        assert_eq!(squares, vec![1, 4, 9]);
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.next(), Some(1));
+
+        let mut short = peekn(0..2);
+        let doubled: Vec<i32> = short.peek_map(5, |x| x * 2);
+        assert_eq!(doubled, vec![0, 2]);
+        let none: Vec<i32> = short.peek_map(0, |x| *x);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_lookahead() {
+        let mut iter = peekn(10..20);
+        let _ = iter.peek_nth(6);
+        let window = iter.lookahead(4);
+        assert_eq!(window.len(), 4);
+        assert!(!window.is_empty());
+        assert_eq!(window.get(0), Some(&10));
+        assert_eq!(window.get(3), Some(&13));
+        assert_eq!(window.get(4), None);
+        assert_eq!(
+            window.iter().copied().collect::<Vec<_>>(),
+            vec![10, 11, 12, 13]
+        );
+        assert_eq!(window.as_slice(), Some(&[10, 11, 12, 13][..]));
+        assert_eq!(iter.next(), Some(10));
+
+        let mut short = peekn(0..2);
+        let window = short.lookahead(4);
+        assert_eq!(window.len(), 2);
+        assert_eq!(window.get(2), None);
+        assert!(short.lookahead(0).is_empty());
+    }
+
+    #[test]
+    fn test_next_if_not_last() {
+        let mut iter = peekn(0..3);
+        assert_eq!(iter.next_if_not_last(|&x| x == 1), None);
+        assert_eq!(iter.next_if_not_last(|&x| x == 0), Some(0));
+        assert_eq!(iter.next_if_not_last(|_| true), Some(1));
+        assert_eq!(iter.next_if_not_last(|_| true), None);
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(iter.next_if_not_last(|_| true), None);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_if_not_last(|_| true), None);
+    }
+
+    #[test]
+    fn test_peek_while_vec() {
+        let mut iter = peekn(0..10);
+        let run = iter.peek_while_vec(|&x| x < 5);
+        assert_eq!(run, vec![0, 1, 2, 3, 4]);
+        let consumed: Vec<_> = iter.by_ref().take(run.len()).collect();
+        assert_eq!(consumed, run);
+        assert_eq!(iter.next(), Some(5));
+
+        let mut iter = peekdn(0..10);
+        let run = iter.peek_while_vec(|&x| x < 5);
+        assert_eq!(run, vec![0, 1, 2, 3, 4]);
+        assert_eq!(iter.front_peeked_len(), 6);
+        let consumed: Vec<_> = iter.by_ref().take(run.len()).collect();
+        assert_eq!(consumed, run);
+
+        let mut iter = peekdn(0..3);
+        let _ = iter.peek_back_nth(2);
+        assert_eq!(iter.peek_while_vec(|_| true), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_sized_try_from_peekdn() {
+        let mut source = peekdn(0..10);
+        let _ = source.peek_front_nth(1);
+        let _ = source.peek_back_nth(0);
+        let mut sized = SizedPeekDN::<_, 2, 1>::try_from(source).ok().unwrap();
+        assert_eq!(sized.front_peeked_len(), 2);
+        assert_eq!(sized.back_peeked_len(), 1);
+        assert_eq!(sized.next(), Some(0));
+        assert_eq!(sized.next_back(), Some(9));
+        assert_eq!(sized.collect::<Vec<_>>(), (1..9).collect::<Vec<_>>());
+
+        let mut source = peekdn(0..10);
+        let _ = source.peek_front_nth(3);
+        let err = SizedPeekDN::<_, 2, 2>::try_from(source).err().unwrap();
+        assert_eq!(err.error(), PeekError::BufferOverflow { capacity: 2 });
+        let mut back = err.into_inner();
+        assert_eq!(back.front_peeked_len(), 4);
+        assert_eq!(back.next(), Some(0));
+
+        let mut source = peekdn(0..10);
+        let _ = source.peek_back_nth(2);
+        let err = SizedPeekDN::<_, 4, 2>::try_from(source).err().unwrap();
+        assert_eq!(err.error(), PeekError::BufferOverflow { capacity: 2 });
+        assert_eq!(err.to_string(), "buffer overflow: capacity is 2");
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_debug_rebuffer_count() {
+        let mut iter = peekn(0..20);
+        let _ = iter.peek_nth(3);
+        iter.clear_peeked();
+        let _ = iter.peek_nth(2);
+        assert_eq!(iter.debug_rebuffer_count(), 1);
+
+        iter.clear_peeked();
+        let _ = iter.peek();
+        assert_eq!(iter.debug_rebuffer_count(), 2);
+
+        // Normal usage: consuming or peeking past the discarded window.
+        let mut iter = peekn(0..20);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.peek_nth(1);
+        iter.clear_peeked();
+        let _ = iter.next();
+        let _ = iter.peek_nth(0);
+        let _ = iter.peek_nth(2);
+        iter.clear_peeked();
+        let _ = iter.peek_nth(5);
+        assert_eq!(iter.debug_rebuffer_count(), 0);
+
+        let cloned = iter.clone();
+        assert_eq!(cloned.debug_rebuffer_count(), 0);
+    }
+
+    #[test]
+    fn test_peeked_make_contiguous() {
+        let mut iter = peekn(0..10);
+        let _ = iter.peek_nth(2);
+        let _ = iter.next();
+        let _ = iter.next_if(|_| false);
+        let _ = iter.peek_nth(4);
+
+        let (a, b) = iter.as_slices();
+        assert_eq!([a, b].concat(), vec![1, 2, 3, 4, 5]);
+
+        let slice = iter.peeked_make_contiguous();
+        assert_eq!(slice, &[1, 2, 3, 4, 5]);
+        slice.reverse();
+        assert_eq!(iter.as_slices().0, &[5, 4, 3, 2, 1]);
+        assert!(iter.as_slices().1.is_empty());
+        assert_eq!(iter.next(), Some(5));
+    }
+
+    #[test]
+    fn test_fully_buffered() {
+        let mut iter = peekdn(0..5);
+        assert!(!iter.fully_buffered());
+        assert_eq!(iter.peek_front_nth(3), Some(&3));
+        assert!(!iter.fully_buffered());
+
+        assert_eq!(iter.peek_front_nth(100), None);
+        assert!(iter.fully_buffered());
+        assert_eq!(iter.front_peeked_len(), 5);
+        assert_eq!(iter.back_peeked_len(), 0);
+
+        // The back end reads from the tail of the front buffer.
+        assert_eq!(iter.peek_back_nth(0), Some(&4));
+        assert_eq!(iter.peek_back_nth(4), Some(&0));
+        assert_eq!(iter.peek_back_nth(5), None);
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let mut iter = peekdn(0..5);
+        let _ = iter.peek_back_nth(9);
+        assert!(iter.fully_buffered());
+        assert_eq!(iter.peek_front_nth(0), Some(&0));
+
+        // Only an observed `None` counts, not the size_hint of the inner iterator.
+        let mut iter = peekdn(0..0);
+        assert!(!iter.fully_buffered());
+        assert_eq!(iter.peek_front(), None);
+        assert!(iter.fully_buffered());
+
+        // No useful size_hint: exhaustion is still observed by the peeks.
+        struct Loose(core::ops::Range<i32>);
+        impl Iterator for Loose {
+            type Item = i32;
+            fn next(&mut self) -> Option<i32> {
+                self.0.next()
+            }
+        }
+        impl DoubleEndedIterator for Loose {
+            fn next_back(&mut self) -> Option<i32> {
+                self.0.next_back()
+            }
+        }
+
+        let mut iter = peekdn(Loose(0..3));
+        assert_eq!(iter.peek_front_nth(2), Some(&2));
+        assert!(!iter.fully_buffered());
+        assert_eq!(iter.peek_front_nth(3), None);
+        assert!(iter.fully_buffered());
+
+        let mut iter = peekdn(Loose(0..3));
+        assert_eq!(iter.peek_back_nth(5), None);
+        assert!(iter.fully_buffered());
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_peek_nth_ok() {
+        let mut iter = peekn(vec![Ok(1), Ok(2), Err("x"), Ok(3)].into_iter());
+        assert_eq!(iter.peek_nth_ok(0), Ok(Some(&1)));
+        assert_eq!(iter.peek_nth_ok(1), Ok(Some(&2)));
+        assert_eq!(iter.peeked_len(), 2);
+
+        assert_eq!(iter.peek_nth_ok(2), Err(&"x"));
+        assert_eq!(iter.peek_nth_ok(3), Err(&"x"));
+        assert_eq!(iter.peeked_len(), 3);
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Err("x")));
+        assert_eq!(iter.peek_nth_ok(0), Ok(Some(&3)));
+        assert_eq!(iter.peek_nth_ok(1), Ok(None));
+    }
+
+    #[test]
+    fn test_peek_shared() {
+        use std::rc::Rc;
+
+        let items: Vec<Rc<str>> = vec!["a".into(), "b".into(), "c".into()];
+        let mut iter = peekn(items.into_iter());
+
+        let first = iter.peek_shared().unwrap();
+        assert_eq!(&*first, "a");
+        assert_eq!(Rc::strong_count(&first), 2);
+
+        let third = iter.peek_nth_shared(2).unwrap();
+        assert_eq!(Rc::strong_count(&third), 2);
+        assert!(Rc::ptr_eq(iter.peek_nth(2).unwrap(), &third));
+        assert_eq!(iter.peek_nth_shared(3), None);
+
+        let consumed = iter.next().unwrap();
+        assert!(Rc::ptr_eq(&consumed, &first));
+        drop(consumed);
+        assert_eq!(Rc::strong_count(&first), 1);
+        assert_eq!(iter.peek().map(|s| &**s), Some("b"));
+    }
+
+    #[test]
+    fn test_peek_ext_traits() {
+        let mut iter = (0..10).filter(|x| x % 2 == 0).peekn();
+        assert_eq!(iter.peek_nth(2), Some(&4));
+
+        let mut sized = (0..10).sizedpeekn::<3>();
+        assert_eq!(sized.peek_nth(2), Some(&2));
+
+        let mut dn = (0..5).map(|x| x * 10).peekdn();
+        assert_eq!(dn.peek_back_nth(1), Some(&30));
+
+        let mut sized_dn = (0..5).sizedpeekdn::<2, 2>();
+        assert_eq!(sized_dn.peek_front_nth(1), Some(&1));
+        assert_eq!(sized_dn.peek_back_nth(1), Some(&3));
+
+        let mut de = (0..5).rev().peekablede();
+        assert_eq!(de.peek_front(), Some(&4));
+        assert_eq!(de.peek_back(), Some(&0));
+    }
+
+    #[test]
+    fn test_find_map_front() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(6);
+        assert_eq!(
+            iter.find_map_front(|&x| (x * x > 10).then_some(x * x)),
+            Some(16)
+        );
+        assert_eq!(iter.peeked_len(), 2);
+        assert_eq!(iter.next(), Some(5));
+
+        let mut short = peekn(0..3);
+        assert_eq!(short.find_map_front(|&x| (x > 5).then_some(x)), None);
+        assert_eq!(short.next(), None);
+
+        let mut dn = peekdn(0..10);
+        let _ = dn.peek_back_nth(1);
+        assert_eq!(dn.find_map_front(|&x| (x * x > 10).then_some(x)), Some(4));
+        assert_eq!(dn.collect::<Vec<_>>(), vec![5, 6, 7, 8, 9]);
+
+        let mut dn = peekdn(0..3);
+        let _ = dn.peek_back();
+        assert_eq!(dn.find_map_front(|&x| (x == 2).then_some(x)), Some(2));
+        assert_eq!(dn.next(), None);
+    }
+
+    #[test]
+    fn test_put_back() {
+        let mut iter = peekn(10..12);
+        let first = iter.next().unwrap();
+        iter.put_back(first * 2);
+        iter.put_back(1);
+        assert_eq!(iter.peek_nth(0), Some(&1));
+        assert_eq!(iter.peek_nth(1), Some(&20));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 20, 11]);
+
+        let mut dn = peekdn(0..4);
+        let _ = dn.peek_front_nth(1);
+        dn.put_back_front(-1);
+        dn.put_back_back(9);
+        dn.put_back_back(8);
+        assert_eq!(dn.peek_front(), Some(&-1));
+        assert_eq!(dn.peek_back(), Some(&8));
+        assert_eq!(dn.collect::<Vec<_>>(), vec![-1, 0, 1, 2, 3, 9, 8]);
+
+        let mut sized = sizedpeekdn::<_, 2, 2>(0..4);
+        sized.put_back_front(-1);
+        sized.put_back_back(9);
+        assert_eq!(sized.peek_front_nth(1), Some(&0));
+        assert_eq!(sized.next_back(), Some(9));
+        assert_eq!(sized.collect::<Vec<_>>(), vec![-1, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer full")]
+    fn test_sized_put_back_full() {
+        let mut sized = sizedpeekdn::<_, 1, 1>(0..4);
+        let _ = sized.peek_front();
+        sized.put_back_front(-1);
+    }
+
+    #[test]
+    fn test_max_peek_index() {
+        assert_eq!(MAX_PEEK_INDEX, usize::MAX - 1);
+
+        let mut iter = peekn(0..3);
+        assert_eq!(iter.peek_nth(MAX_PEEK_INDEX), None);
+        assert_eq!(iter.peek_nth(usize::MAX), None);
+        assert_eq!(iter.peek_nth_mut(usize::MAX), None);
+        assert_eq!(iter.peek_nth_if(usize::MAX, |&x| Some(x)), None);
+
+        let mut dn = peekdn(0..3);
+        assert_eq!(dn.peek_front_nth(MAX_PEEK_INDEX), None);
+        assert_eq!(dn.peek_front_nth(usize::MAX), None);
+        assert_eq!(dn.peek_back_nth(usize::MAX), None);
+        assert_eq!(dn.peek_front_nth_mut(usize::MAX), None);
+        assert_eq!(dn.peek_back_nth_mut(usize::MAX), None);
+        assert_eq!(dn.peek_back_nth_bounded(usize::MAX, 10), None);
+
+        let mut sized = sizedpeekn::<_, 2>(0..3);
+        assert_eq!(sized.peek_nth(usize::MAX), None);
+        assert_eq!(sized.peek_nth_mut(usize::MAX), None);
+
+        let mut sized_dn = sizedpeekdn::<_, 2, 2>(0..3);
+        assert_eq!(sized_dn.peek_front_nth(usize::MAX), None);
+        assert_eq!(sized_dn.peek_back_nth(usize::MAX), None);
+        assert_eq!(sized_dn.peek_front_nth_mut(usize::MAX), None);
+        assert_eq!(sized_dn.peek_back_nth_mut(usize::MAX), None);
+
+        let mut results = peekn(vec![Ok::<_, ()>(1)].into_iter());
+        assert_eq!(results.peek_nth_ok(usize::MAX), Ok(None));
+    }
+
+    #[test]
+    fn test_peek_range_unbounded_end_uses_buffer() {
+        let mut iter = peekn(0..);
+        assert_eq!(iter.peek_range(..).count(), 0);
+
+        let _ = iter.peek_nth(2);
+        assert_eq!(
+            iter.peek_range(..).copied().collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            iter.peek_range(1..).copied().collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        for x in iter.peek_range_mut(..) {
+            *x *= 10;
+        }
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(10));
+    }
+
+    #[test]
+    fn test_snapshot_ends() {
+        let mut iter = peekdn(1..=5);
+        let (front, back) = iter.snapshot_ends();
+        assert_eq!((front, back), (Some(1), Some(5)));
+        assert_eq!(iter.next(), front);
+        assert_eq!(iter.next_back(), back);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        let mut single = peekdn(std::iter::once(String::from("x")));
+        let (front, back) = single.snapshot_ends();
+        assert_eq!(front.as_deref(), Some("x"));
+        assert_eq!(back.as_deref(), Some("x"));
+        assert_eq!(single.count(), 1);
+
+        let mut de = peekablede(1..=5);
+        assert_eq!(de.snapshot_ends(), (Some(1), Some(5)));
+        assert_eq!(de.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(peekablede(0..0).snapshot_ends(), (None, None));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DropCounter {
+        id: u32,
+        drops: std::rc::Rc<std::cell::RefCell<Vec<u32>>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.borrow_mut().push(self.id);
+        }
+    }
+
+    fn drop_counters(n: u32) -> (Vec<DropCounter>, std::rc::Rc<std::cell::RefCell<Vec<u32>>>) {
+        let drops = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let items = (0..n)
+            .map(|id| DropCounter {
+                id,
+                drops: drops.clone(),
+            })
+            .collect();
+        (items, drops)
+    }
+
+    #[test]
+    fn test_sized_non_copy_items_drop_once() {
+        let (items, drops) = drop_counters(6);
+        let mut iter = sizedpeekn::<_, 4>(items.into_iter());
+        let _ = iter.peek_nth(3);
+        let first = iter.next().unwrap();
+        let _ = iter.next_if(|_| false); // wraps the ring head
+        assert_eq!(iter.peek_nth(1).map(|d| d.id), Some(2));
+        drop(first);
+        assert_eq!(*drops.borrow(), vec![0]);
+
+        iter.drain_peeked(1);
+        assert_eq!(*drops.borrow(), vec![0, 1]);
+
+        let mut cloned = iter.clone();
+        assert_eq!(cloned.peek_nth(1), iter.peek_nth(1));
+        drop(cloned);
+        assert_eq!(drops.borrow().len(), 6);
+
+        drop(iter);
+        let mut seen = drops.borrow().clone();
+        seen.sort();
+        assert_eq!(seen, vec![0, 1, 2, 2, 3, 3, 4, 4, 5, 5]);
+    }
+
+    struct PanicOnDrop {
+        id: u32,
+        panics: bool,
+        drops: std::rc::Rc<std::cell::RefCell<Vec<u32>>>,
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            self.drops.borrow_mut().push(self.id);
+            if self.panics {
+                panic!("PanicOnDrop {}", self.id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sized_drain_panicking_drop_never_drops_twice() {
+        let drops = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let items = (0..5).map(|id| PanicOnDrop {
+            id,
+            panics: id == 0,
+            drops: drops.clone(),
+        });
+        let mut iter = sizedpeekn::<_, 4>(items);
+        let _ = iter.peek_nth(3);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            iter.drain_peeked(2);
+        }));
+        assert!(result.is_err());
+        assert_eq!(iter.peek().map(|d| d.id), Some(2));
+
+        drop(iter);
+        let mut seen = drops.borrow().clone();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), drops.borrow().len());
+        assert!(seen.contains(&0) && seen.contains(&2) && seen.contains(&3));
+    }
+
+    #[test]
+    fn test_sized_strings_and_clear() {
+        let mut iter = sizedpeekn::<_, 4>(["a", "b", "c"].iter().map(|s| s.to_string()));
+        assert_eq!(iter.peek_nth(2).map(String::as_str), Some("c"));
+        assert_eq!(iter.next().as_deref(), Some("a"));
+
+        let (items, drops) = drop_counters(5);
+        let mut dn = sizedpeekdn::<_, 2, 2>(items.into_iter());
+        let _ = dn.peek_front_nth(1);
+        let _ = dn.peek_back_nth(1);
+        dn.clear_peeked();
+        let mut seen = drops.borrow().clone();
+        seen.sort();
+        assert_eq!(seen, vec![0, 1, 3, 4]);
+        assert_eq!(dn.next().map(|d| d.id), Some(2));
+        assert_eq!(drops.borrow().len(), 5);
+        assert!(dn.next().is_none());
+    }
+
+    #[test]
+    fn test_peek_range_enumerate() {
+        let mut iter = peekn(0..10);
+        let pairs: Vec<_> = iter.peek_range_enumerate(3..6).collect();
+        assert_eq!(pairs, vec![(3, &3), (4, &4), (5, &5)]);
+
+        let pairs: Vec<_> = iter.peek_range_enumerate(..=1).collect();
+        assert_eq!(pairs, vec![(0, &0), (1, &1)]);
+        assert_eq!(iter.peek_range_enumerate(4..).last(), Some((5, &5)));
+
+        let _ = iter.next();
+        assert_eq!(iter.peek_range_enumerate(2..3).next(), Some((2, &3)));
+    }
+
+    #[test]
+    fn test_sized_try_peek_nth() {
+        use peeknth::PeekError;
+
+        let mut iter = sizedpeekn::<_, 3>(0..2);
+        assert_eq!(iter.try_peek_nth(1), Ok(Some(&1)));
+        assert_eq!(iter.try_peek_nth(2), Ok(None));
+        let err = iter.try_peek_nth(3).unwrap_err();
+        assert_eq!(
+            err,
+            PeekError::OverCapacity {
+                requested: 3,
+                capacity: 3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "peek index 3 is out of bounds for a buffer of capacity 3"
+        );
+
+        if let Ok(Some(x)) = iter.try_peek_nth_mut(0) {
+            *x = 10;
+        }
+        assert!(iter.try_peek_nth_mut(usize::MAX).is_err());
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 1]);
+    }
+
+    #[test]
+    fn test_peekn_chunks() {
+        let chunks: Vec<_> = peekn(0..7).chunks(3).collect();
+        assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        let mut iter = peekn(0..4);
+        assert_eq!(iter.peek_nth(2), Some(&2));
+        let chunks: Vec<_> = iter.chunks(2).collect();
+        assert_eq!(chunks, vec![vec![0, 1], vec![2, 3]]);
+
+        assert_eq!(peekn(core::iter::empty::<u8>()).chunks(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_peekn_chunks_zero() {
+        let _ = peekn(0..3).chunks(0);
+    }
+
+    #[test]
+    fn test_peekn_double_ended() {
+        let mut iter = peekn(0..6);
+        assert_eq!(iter.peek_nth(2), Some(&2));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.peek_nth(1), Some(&2));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.peek_nth(1), None);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        let items: Vec<_> = peekn(0..5).rev().collect();
+        assert_eq!(items, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_lookahead_to_vec_wrapped() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        let window = iter.lookahead(4);
+        assert_eq!(window.as_slice(), None);
+        assert_eq!(window.to_vec(), vec![2, 3, 4, 5]);
+
+        let mut out = [0; 6];
+        assert_eq!(window.copy_into(&mut out), 4);
+        assert_eq!(out, [2, 3, 4, 5, 0, 0]);
+
+        let mut short = [0; 3];
+        assert_eq!(window.copy_into(&mut short), 3);
+        assert_eq!(short, [2, 3, 4]);
+
+        let window = iter.lookahead(1);
+        assert_eq!(window.to_vec(), vec![2]);
+    }
+
+    #[test]
+    fn test_peekn_as_slices() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        assert_eq!(iter.while_peek(|&x| x < 6), 4);
+
+        let (head, tail) = iter.as_slices();
+        let all: Vec<_> = head.iter().chain(tail).copied().collect();
+        assert_eq!(all, vec![2, 3, 4, 5, 6]);
+
+        let (head, tail) = iter.as_mut_slices();
+        for x in head.iter_mut().chain(tail) {
+            *x *= 10;
+        }
+        assert_eq!(iter.next(), Some(20));
+        assert_eq!(iter.peek_nth(3), Some(&60));
+    }
+
+    #[test]
+    fn test_peekn_boxed() {
+        let boxed_iter: Box<dyn Iterator<Item = i32>> = Box::new(vec![1, 2, 3].into_iter());
+        let mut iter = peekn(boxed_iter);
+        assert_eq!(iter.peek_nth(1), Some(&2));
+        assert_eq!(iter.next(), Some(1));
+
+        let sources: Vec<Box<dyn Iterator<Item = i32>>> =
+            vec![Box::new(0..3), Box::new([7, 8].into_iter().rev())];
+        let mut peeked: Vec<BoxedPeekN<i32>> = sources.into_iter().map(peekn_boxed).collect();
+        assert_eq!(peeked[0].peek_nth(2), Some(&2));
+        assert_eq!(peeked[1].peek(), Some(&8));
+        assert_eq!(peeked[1].by_ref().collect::<Vec<_>>(), vec![8, 7]);
+        assert_eq!(
+            peeked[0].while_next(|&x| x < 2).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_into_from_parts() {
+        let mut iter = peekn(0..5);
+        let _ = iter.peek_nth(1);
+        let (inner, buffer, tail) = iter.into_parts();
+        assert_eq!(buffer, [0, 1]);
+        assert_eq!(tail, None);
+        let mut iter = PeekN::from_parts(inner, buffer, tail);
+
+        assert_eq!(iter.peek_last(), Some(&4));
+        let (inner, buffer, tail) = iter.into_parts();
+        assert_eq!(inner.collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(buffer, [0, 1]);
+        assert_eq!(tail, Some(4));
+
+        let mut iter = peekn(0..5);
+        let _ = iter.peek_nth(1);
+        let _ = iter.peek_last();
+        let (inner, buffer, tail) = iter.into_parts();
+        let iter = PeekN::from_parts(inner, buffer, tail);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+        let mut iter = peekdn(0..6);
+        let _ = iter.peek_front_nth(1);
+        let _ = iter.peek_back_nth(0);
+        let (inner, front, back) = iter.into_parts();
+        assert_eq!((front.clone(), back.clone()), ([0, 1].into(), [5].into()));
+        let mut iter = PeekDN::from_parts(inner, front, back);
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_peekdn_find_back() {
+        let mut iter = peekdn(0..10);
+        assert_eq!(iter.find_back(|&x| x % 2 == 0), Some(8));
+        assert_eq!(iter.peek_back(), Some(&7));
+
+        let _ = iter.peek_front_nth(6);
+        assert_eq!(iter.find_back(|&x| x < 3), Some(2));
+        assert_eq!(iter.peek_back(), Some(&1));
+        assert_eq!(iter.find_back(|&x| x > 5), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_peekn_collect_peeked() {
+        let mut iter = peekn(0..10);
+        assert_eq!(iter.collect_peeked(), Vec::<i32>::new());
+        let _ = iter.peek_nth(3);
+        assert_eq!(iter.collect_peeked(), vec![0, 1, 2, 3]);
+        assert_eq!(iter.peeked_len(), 0);
+        assert_eq!(iter.next(), Some(4));
+
+        let _ = iter.peek_nth(2);
+        let drained: Vec<_> = iter.drain_peeked_iter(10).collect();
+        assert_eq!(drained, vec![5, 6, 7]);
+        assert_eq!(iter.peeked_len(), 0);
+
+        let _ = iter.peek_nth(1);
+        assert_eq!(iter.drain_peeked_iter(1).next(), Some(8));
+        assert_eq!(iter.peeked_len(), 1);
+        assert_eq!(iter.next(), Some(9));
+    }
+
+    #[test]
+    fn test_peekdn_front_slice_mut_wrapped() {
+        let mut iter = peekdn(0..20);
+        let _ = iter.peek_front_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        let _ = iter.peek_front_nth(3);
+
+        let (head, tail) = iter.peek_front_slice_mut(1..4);
+        assert_eq!((head.len(), tail.len()), (1, 2));
+        head[0] = 30;
+        tail.copy_from_slice(&[40, 50]);
+
+        let (head, tail) = iter.peek_front_slice_mut(..);
+        assert_eq!([&*head, &*tail].concat(), vec![2, 30, 40, 50]);
+        let (head, tail) = iter.peek_front_slice_mut(3..3);
+        assert!(head.is_empty() && tail.is_empty());
+
+        let mut short = peekdn(0..3);
+        let (head, tail) = short.peek_front_slice_mut(1..10);
+        assert_eq!((&*head, &*tail), (&[1, 2][..], &[][..]));
+    }
+
+    #[test]
+    fn test_size_hint_includes_buffer() {
+        let mut iter = peekn((0..10).filter(|_| true));
+        let _ = iter.peek_nth(2);
+        assert_eq!(iter.size_hint(), (3, Some(10)));
+        assert_eq!(iter.clone().count(), 10);
+        assert_eq!(iter.clone().last(), Some(9));
+
+        let mut short = peekn(0..3);
+        let _ = short.peek_nth(5);
+        assert_eq!(short.size_hint(), (3, Some(3)));
+        assert_eq!(short.len(), 3);
+        assert_eq!(short.clone().last(), Some(2));
+        assert_eq!(short.count(), 3);
+        assert_eq!(peekn(0..0).last(), None);
+
+        let mut sized = sizedpeekn::<_, 4>(0..5);
+        let _ = sized.peek_nth(1);
+        assert_eq!(sized.size_hint(), (5, Some(5)));
+
+        let mut dn = peekdn(0..6);
+        let _ = dn.peek_front_nth(1);
+        let _ = dn.peek_back_nth(0);
+        assert_eq!(dn.size_hint(), (6, Some(6)));
+
+        let mut sdn = sizedpeekdn::<_, 2, 2>(0..4);
+        let _ = sdn.peek_back_nth(1);
+        assert_eq!(sdn.size_hint(), (4, Some(4)));
+
+        let mut de = peekablede(0..3);
+        let _ = de.peek_front();
+        let _ = de.peek_back();
+        assert_eq!(de.size_hint(), (3, Some(3)));
+        assert_eq!(
+            peekablede(core::iter::once(1).filter(|_| true)).size_hint(),
+            (0, Some(1))
+        );
+    }
+
+    #[test]
+    fn test_peekn_split_once() {
+        let mut iter = peekn([1, 2, 0, 3].into_iter());
+        assert_eq!(iter.split_once(|&x| x == 0), Some(vec![1, 2]));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = peekn([0, 5].into_iter());
+        assert_eq!(iter.split_once(|&x| x == 0), Some(vec![]));
+        assert_eq!(iter.peek(), Some(&5));
+
+        let mut iter = peekn([1, 2, 3].into_iter());
+        assert_eq!(iter.split_once(|&x| x == 0), None);
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_peekn_peek_chunk() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        assert_eq!(iter.peek_chunk::<4>(), Some([&2, &3, &4, &5]));
+        assert_eq!(iter.peek_chunk::<0>(), Some([]));
+
+        let mut iter = peekn(0..5);
+        assert_eq!(iter.next_chunk::<2>(), Some([0, 1]));
+        assert_eq!(iter.peek_chunk::<3>(), Some([&2, &3, &4]));
+        assert_eq!(iter.peek_chunk::<4>(), None);
+        assert_eq!(iter.next_chunk::<4>(), None);
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.next_chunk::<3>(), Some([2, 3, 4]));
+        assert_eq!(iter.next_chunk::<0>(), Some([]));
+        assert_eq!(iter.next_chunk::<1>(), None);
+    }
+
+    #[test]
+    fn test_peekn_next_if_rollback_matches_peekable() {
+        let mut iter = peekn(0..200);
+        let mut model = (0..200).peekable();
+        for step in 0..400 {
+            let modulus = step % 5 + 1;
+            assert_eq!(
+                iter.next_if(|&x| x % modulus == 0),
+                model.next_if(|&x| x % modulus == 0)
+            );
+            assert_eq!(iter.peek(), model.peek());
+            if step % 3 == 0 {
+                assert_eq!(iter.next(), model.next());
+            }
+            if step % 7 == 0 {
+                let _ = iter.peek_nth(step % 4);
+            }
+        }
+        assert!(iter.eq(model));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let mut iter = peekn(0..10);
+        let _ = iter.peek_nth(2);
+        let json = serde_json::to_string(&iter).unwrap();
+        let mut restored: PeekN<core::ops::Range<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.peeked_len(), 3);
+        assert_eq!(restored.peek_nth(4), iter.peek_nth(4));
+        assert!(restored.eq(iter));
+
+        let mut sized = sizedpeekn::<_, 4>(0..10);
+        let _ = sized.next_if(|_| false);
+        let _ = sized.peek_nth(3);
+        let json = serde_json::to_string(&sized).unwrap();
+        let restored: SizedPeekN<core::ops::Range<i32>, 4> = serde_json::from_str(&json).unwrap();
+        assert!(restored.eq(sized));
+        let too_long = r#"{"iter":{"start":0,"end":3},"buffer":[1,2,3]}"#;
+        assert!(serde_json::from_str::<SizedPeekN<core::ops::Range<i32>, 2>>(too_long).is_err());
+        let fits = r#"{"iter":{"start":5,"end":6},"buffer":[1,2]}"#;
+        let restored: SizedPeekN<core::ops::Range<i32>, 2> = serde_json::from_str(fits).unwrap();
+        assert_eq!(restored.collect::<Vec<_>>(), vec![1, 2, 5]);
+
+        let mut de = peekablede(0..5);
+        let _ = de.peek_back();
+        let json = serde_json::to_string(&de).unwrap();
+        let mut restored: PeekableDE<core::ops::Range<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.peek_back(), Some(&4));
+        assert!(restored.eq(de));
+    }
+
+    #[test]
+    fn test_sized_peek_range_full_wrapped_buffer() {
+        let mut iter = sizedpeekn::<_, 4>(0..10);
+        let _ = iter.next_if(|_| false);
+        let _ = iter.peek_nth(3);
+        let items: Vec<_> = iter.peek_range(0..4).copied().collect();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        for x in iter.peek_range_mut(1..4) {
+            *x *= 10;
+        }
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![0, 10, 20, 30, 4, 5, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn test_peekn_peek_contiguous_mut() {
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        let _ = iter.peek_nth(3);
+        assert!(!iter.as_slices().1.is_empty());
+
+        let slice = iter.peek_contiguous_mut(4);
+        assert_eq!(slice, &[2, 3, 4, 5]);
+        slice.reverse();
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), Some(4));
+
+        let mut short = peekn(0..2);
+        assert_eq!(short.peek_contiguous_mut(5), &mut [0, 1]);
+        assert!(short.peek_contiguous_mut(0).is_empty());
+    }
+
+    #[test]
+    fn test_hash_consistent_with_eq() {
+        use std::collections::HashSet;
+        use std::hash::{BuildHasher, Hash, RandomState};
+
+        fn hash_of<T: Hash>(state: &RandomState, value: &T) -> u64 {
+            state.hash_one(value)
+        }
+        let state = RandomState::new();
+
+        let mut a = peekn(0..10);
+        let _ = a.peek_nth(2);
+        let mut b = peekn(0..10);
+        let _ = b.next_if(|_| false);
+        let _ = b.peek_nth(2);
+        assert!(a == b);
+        assert_eq!(hash_of(&state, &a), hash_of(&state, &b));
+        let positions: HashSet<_> = [a.clone(), b, peekn(1..10)].into_iter().collect();
+        assert_eq!(positions.len(), 2);
+
+        let mut s1 = sizedpeekn::<_, 3>(0..5);
+        let _ = s1.peek_nth(2);
+        let mut s2 = sizedpeekn::<_, 3>(0..5);
+        let _ = s2.next_if(|_| false);
+        let _ = s2.peek_nth(2);
+        assert!(s1 == s2);
+        assert_eq!(hash_of(&state, &s1), hash_of(&state, &s2));
+        let _ = s2.next();
+        assert!(s1 != s2);
+
+        let mut d1 = peekdn(0..6);
+        let _ = d1.peek_back_nth(1);
+        let d2 = d1.clone();
+        assert_eq!(hash_of(&state, &d1), hash_of(&state, &d2));
+        let _ = d1.peek_front();
+        assert!(d1 != d2);
+
+        let mut sd1 = sizedpeekdn::<_, 2, 2>(0..6);
+        let _ = sd1.peek_front_nth(1);
+        let sd2 = sd1.clone();
+        assert_eq!(hash_of(&state, &sd1), hash_of(&state, &sd2));
+
+        let mut e1 = peekablede(0..3);
+        let _ = e1.peek_back();
+        let e2 = e1.clone();
+        assert_eq!(hash_of(&state, &e1), hash_of(&state, &e2));
+    }
+
+    #[test]
+    fn test_peekablede_forward() {
+        let mut iter = peekablede_forward(0..5);
+        assert_eq!(iter.peek_front(), Some(&0));
+        assert_eq!(iter.buffered_len(), 1);
+        assert_eq!(iter.next(), Some(0));
+        assert!(!iter.is_fully_buffered());
+
+        assert_eq!(iter.peek_back(), Some(&4));
+        assert!(iter.is_fully_buffered());
+        assert_eq!(iter.buffered_len(), 4);
+        assert_eq!(iter.next_back_if(|&x| x == 3), None);
+        assert_eq!(iter.next_back_if_eq(&4), Some(4));
+        assert_eq!(iter.next_if_eq(&1), Some(1));
+        if let Some(x) = iter.peek_back_mut() {
+            *x = 30;
+        }
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 30]);
+
+        let mut streamed = peekablede_forward(0..);
+        assert_eq!(streamed.peek_front(), Some(&0));
+        assert_eq!(streamed.next_if(|&x| x > 0), None);
+        assert_eq!(streamed.nth(10), Some(10));
+        assert_eq!(streamed.buffered_len(), 0);
+    }
+
+    #[test]
+    fn test_next_if_map_keeps_position_on_none() {
+        let mut iter = peekn(["1", "x", "2"].into_iter());
+        assert_eq!(iter.next_if_map(|s| s.parse::<i32>().ok()), Some(1));
+        assert_eq!(iter.next_if_map(|s| s.parse::<i32>().ok()), None);
+        assert_eq!(iter.peeked_len(), 1);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some("x"));
+        assert_eq!(iter.next_if_map(|s| s.parse::<i32>().ok()), Some(2));
+        assert_eq!(iter.next_if_map(|_| Some(())), None);
+
+        let mut dn = peekdn(0..5);
+        assert_eq!(
+            dn.next_back_if_map(|&x| (x > 3).then_some(x * 10)),
+            Some(40)
+        );
+        assert_eq!(dn.next_back_if_map(|&x| (x > 3).then_some(x * 10)), None);
+        assert_eq!(dn.next_if_map(|&x| (x == 1).then_some(x)), None);
+        assert_eq!(dn.len(), 4);
+        assert_eq!(dn.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let mut de = peekablede(0..3);
+        assert_eq!(de.next_back_if_map(|&x| (x == 0).then_some(x)), None);
+        assert_eq!(
+            de.next_if_map(|&x| (x == 0).then_some("zero")),
+            Some("zero")
+        );
+        assert_eq!(de.len(), 2);
+        assert_eq!(de.next_back(), Some(2));
+        assert_eq!(de.next_back_if_map(|&x| Some(x + 1)), Some(2));
+        assert_eq!(de.next(), None);
+    }
+
+    #[test]
+    fn test_sized_absolute_position() {
+        let mut iter = sizedpeekn::<_, 3>(0..10);
+        assert_eq!(iter.absolute_position(), 0);
+        let _ = iter.next();
+        let _ = iter.peek_nth(2);
+        let _ = iter.next();
+        let _ = iter.peek();
+        let _ = iter.next();
+        assert_eq!(iter.absolute_position(), 3);
+
+        assert_eq!(iter.next_if(|_| false), None);
+        assert_eq!(iter.absolute_position(), 3);
+        assert_eq!(iter.next_if_eq(&3), Some(3));
+        assert_eq!(iter.while_next(|&x| x < 6).count(), 2);
+        assert_eq!(iter.absolute_position(), 6);
+
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.absolute_position(), 10);
+        let _ = iter.next();
+        assert_eq!(iter.absolute_position(), 10);
+    }
+
+    #[test]
+    fn test_peek_find() {
+        let mut iter = peekn([1, 2, 0, 3, 0].into_iter());
+        assert_eq!(iter.peek_find(|&x| x == 0), Some((2, &0)));
+        assert_eq!(iter.peeked_len(), 3);
+        // The match is already buffered, so nothing more is pulled.
+        assert_eq!(iter.peek_find(|&x| x == 2), Some((1, &2)));
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.peek_find(|&x| x > 5), None);
+        assert_eq!(iter.peeked_len(), 5);
+        assert_eq!(iter.next(), Some(1));
+
+        let mut endless = peekn(0..);
+        assert_eq!(endless.peek_find_within(3, |&x| x == 3), None);
+        assert_eq!(endless.peeked_len(), 3);
+        assert_eq!(endless.peek_find_within(4, |&x| x == 3), Some((3, &3)));
+        assert_eq!(endless.peek_find_within(0, |_| true), None);
+
+        let mut dn = peekdn(0..6);
+        assert_eq!(dn.peek_front_find(|&x| x == 4), Some((4, &4)));
+        assert_eq!(dn.peek_back_find(|&x| x == 1), Some((4, &1)));
+        assert_eq!(dn.peek_back_find(|&x| x > 9), None);
+        assert_eq!(dn.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_peekn_peek_adaptive() {
+        use core::ops::ControlFlow;
+
+        let mut calls = 0;
+        let mut iter = peekn([5, 6, 7, 0, 8].into_iter());
+        let offset = iter.peek_adaptive(|window| {
+            calls += 1;
+            match window.iter().position(|&x| x == 0) {
+                Some(i) => ControlFlow::Break(i),
+                None => ControlFlow::Continue(2),
+            }
+        });
+        assert_eq!(offset, Some(3));
+        assert_eq!(calls, 3);
+        assert_eq!(iter.peeked_len(), 4);
+        assert_eq!(iter.next(), Some(5));
+
+        let mut iter = peekn(1..4);
+        let missing = iter.peek_adaptive(|window| match window.iter().position(|&x| x == 0) {
+            Some(i) => ControlFlow::Break(i),
+            None => ControlFlow::<usize, usize>::Continue(1),
+        });
+        assert_eq!(missing, None);
+        assert_eq!(iter.peeked_len(), 3);
+
+        let first = peekn(0..).peek_adaptive(|window| match window.get(0) {
+            Some(&x) => ControlFlow::Break(x),
+            None => ControlFlow::Continue(1),
+        });
+        assert_eq!(first, Some(0));
+        assert_eq!(
+            peekn(0..).peek_adaptive(|_| ControlFlow::<(), _>::Continue(0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_named_peek_range_types() {
+        struct Window<'a> {
+            items: PeekRange<'a, i32>,
+        }
+
+        let mut iter = peekn(0..);
+        let _ = iter.peek_nth(3);
+        let _ = iter.next();
+        let _ = iter.next();
+        let window = Window {
+            items: iter.peek_range(1..5),
+        };
+        assert_eq!(window.items.len(), 4);
+        assert_eq!(window.items.rev().collect::<Vec<_>>(), vec![&6, &5, &4, &3]);
+
+        let mut range: PeekRangeMut<'_, i32> = iter.peek_range_mut(0..3);
+        *range.next_back().unwrap() = 40;
+        *range.next().unwrap() = 20;
+        assert_eq!(range.len(), 1);
+        assert_eq!(range.next(), Some(&mut 3));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+        assert_eq!(
+            iter.peek_range(..3).copied().collect::<Vec<_>>(),
+            vec![20, 3, 40]
+        );
+
+        let mut sized = sizedpeekn::<_, 4>(0..10);
+        let _ = sized.next_if(|_| false);
+        let range: PeekRange<'_, i32> = sized.peek_range(1..4);
+        assert_eq!(range.copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        let range: PeekRangeMut<'_, i32> = sized.peek_range_mut(0..2);
+        assert_eq!(range.len(), 2);
+    }
+
+    #[test]
+    fn test_fill_peeked_with() {
+        let mut iter = peekn(0..6);
+        let _ = iter.peek_nth(5);
+        iter.fill_peeked_with(2..4, || -1);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, -1, -1, 4, 5]);
+
+        let mut iter = peekn(0..3);
+        let mut next = 10;
+        iter.fill_peeked_with(1..8, || {
+            next += 1;
+            next
+        });
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 11, 12]);
+
+        let mut sized = sizedpeekn::<_, 4>(0..6);
+        sized.fill_peeked_with(2..4, || 0);
+        assert_eq!(sized.peeked_len(), 4);
+        sized.fill_peeked_with(.., || 7);
+        assert_eq!(sized.collect::<Vec<_>>(), vec![7, 7, 7, 7, 4, 5]);
+    }
+
+    #[test]
+    fn test_sized_capacity_queries() {
+        assert_eq!(SizedPeekN::<core::ops::Range<u8>, 3>::CAPACITY, 3);
+
+        let mut iter = sizedpeekn::<_, 3>(0..10);
+        assert_eq!((iter.remaining_capacity(), iter.is_full()), (3, false));
+        let _ = iter.peek_nth(1);
+        assert_eq!((iter.remaining_capacity(), iter.is_full()), (1, false));
+        let _ = iter.peek_nth(2);
+        assert_eq!((iter.remaining_capacity(), iter.is_full()), (0, true));
+        let _ = iter.next();
+        assert!(!iter.is_full());
+        assert_eq!(iter.next_if(|_| false), None);
+        assert_eq!(iter.remaining_capacity(), 1);
+
+        let mut dn = sizedpeekdn::<_, 2, 1>(0..10);
+        assert!(!dn.front_is_full() && !dn.back_is_full());
+        let _ = dn.peek_front_nth(1);
+        assert!(dn.front_is_full());
+        assert_eq!(dn.front_remaining_capacity(), 0);
+        let _ = dn.peek_back();
+        assert!(dn.back_is_full());
+        let _ = dn.next_back();
+        assert_eq!(dn.back_remaining_capacity(), 1);
+        assert!(!dn.back_is_full());
+    }
+
+    #[test]
+    fn test_peekn_peek_min_max_copied() {
+        let data = [7, 3, 9, 1, 5, 0, 8];
+        let mut iter = peekn(data.iter().copied());
+        assert_eq!(iter.peek_min_copied(5), Some(1));
+        assert_eq!(iter.peek_max_copied(5), Some(9));
+        assert_eq!(iter.peek_max_copied(2), Some(7));
+        assert_eq!(iter.peek_min_copied(0), None);
+        assert_eq!(iter.peek_min_copied(100), Some(0));
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.next(), Some(7));
+
+        assert_eq!(peekn(core::iter::empty::<u8>()).peek_max_copied(3), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_sized_peek_range_empty_ranges() {
+        let mut iter = sizedpeekn::<_, 8>(0..10);
+        assert_eq!(iter.peek_range(2..2).count(), 0);
+        assert_eq!(iter.peek_range(5..0).count(), 0);
+        assert_eq!(iter.peek_range_mut(2..2).count(), 0);
+        assert_eq!(iter.peek_range_mut(5..0).count(), 0);
+
+        let mut it = sizedpeekdn::<_, 8, 8>(0..10);
+        assert_eq!(it.peek_front_range(2..2).count(), 0);
+        assert_eq!(it.peek_front_range(5..0).count(), 0);
+        assert_eq!(it.peek_back_range(2..2).count(), 0);
+        assert_eq!(it.peek_back_range(5..0).count(), 0);
+        assert_eq!(it.peek_front_range_mut(2..2).count(), 0);
+        assert_eq!(it.peek_front_range_mut(5..0).count(), 0);
+        assert_eq!(it.peek_back_range_mut(2..2).count(), 0);
+        assert_eq!(it.peek_back_range_mut(5..0).count(), 0);
+    }
+
+    #[test]
+    fn test_peekn_peek_windows() {
+        let mut iter = peekn(0..5);
+        let windows: Vec<[i32; 3]> = iter.peek_windows::<3>(2).map(|w| w.map(|&x| x)).collect();
+        assert_eq!(windows, vec![[0, 1, 2], [1, 2, 3]]);
+        assert_eq!(iter.peek_windows::<3>(10).count(), 3);
+        assert_eq!(iter.peek_windows::<6>(1).count(), 0);
+        assert_eq!(iter.next(), Some(0));
+    }
+
+    #[test]
+    fn test_peekn_peek_cycle_len() {
+        let mut iter = peekn([1, 2, 1, 2, 1, 2].into_iter());
+        assert_eq!(iter.peek_cycle_len(3), Some(2));
+        assert_eq!(iter.peek_cycle_len(1), None);
+        assert_eq!(iter.next(), Some(1));
+
+        let mut short = peekn([7, 7, 8].into_iter());
+        assert_eq!(short.peek_cycle_len(1), Some(1));
+        assert_eq!(short.peek_cycle_len(0), None);
+
+        let mut too_few = peekn([1, 2, 1].into_iter());
+        assert_eq!(too_few.peek_cycle_len(2), None);
+    }
+
+    #[test]
+    fn test_peekn_scan_peeked() {
+        let mut iter = peekn(0..);
+        let scanned: Vec<_> = iter.scan_peeked().take(3).collect();
+        assert_eq!(scanned, vec![0, 1, 2]);
+        assert_eq!(iter.peeked_len(), 3);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.scan_peeked().take(1).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(iter.peeked_len(), 2);
+
+        let mut short = peekn(0..2);
+        assert_eq!(short.scan_peeked().count(), 2);
+        assert_eq!(short.collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_peekdn_peek_nth_absolute() {
+        let mut iter = peekdn(0..=9);
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert_eq!(iter.peek_back_nth(2), Some(&7));
+        assert_eq!(iter.peek_nth(0), Some(&0));
+        assert_eq!(iter.peek_nth(5), Some(&5));
+        assert_eq!(iter.peek_nth(6), Some(&6));
+        assert_eq!(iter.peek_nth(7), Some(&7));
+        assert_eq!(iter.peek_nth(9), Some(&9));
+        assert_eq!(iter.peek_nth(10), None);
+
+        assert_eq!(iter.next_back(), Some(9));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.peek_nth(7), Some(&8));
+        assert_eq!(iter.peek_nth(8), None);
+        assert_eq!(iter.collect::<Vec<_>>(), (1..=8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rotate_peeked() {
+        let mut iter = peekn(0..4);
+        let _ = iter.peek_nth(3);
+        iter.rotate_peeked_left(1);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 0]);
+
+        let mut iter = peekn(0..6);
+        let _ = iter.peek_nth(3);
+        iter.rotate_peeked_right(1);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![3, 0, 1, 2, 4, 5]);
+
+        let mut sized = sizedpeekn::<_, 6>(0..10);
+        let _ = sized.peek_nth(3);
+        sized.rotate_peeked_left(1);
+        assert_eq!(
+            sized.by_ref().take(5).collect::<Vec<_>>(),
+            vec![1, 2, 3, 0, 4]
+        );
+
+        // A full ring whose head has moved.
+        let mut full = sizedpeekn::<_, 4>(0..10);
+        full.next();
+        let _ = full.peek_nth(3);
+        full.rotate_peeked_left(3);
+        full.rotate_peeked_right(1);
+        assert_eq!(full.collect::<Vec<_>>(), vec![3, 4, 1, 2, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_peeked_left: mid (3) exceeds peeked length (2)")]
+    fn test_rotate_peeked_out_of_bounds() {
+        let mut iter = sizedpeekn::<_, 4>(0..10);
+        let _ = iter.peek_nth(1);
+        iter.rotate_peeked_left(3);
+    }
+
+    #[test]
+    fn test_peekablede_two_slot_peek() {
+        let mut iter = peekablede(0..6);
+        assert_eq!(iter.peek_front_nth(0), Some(&0));
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert_eq!(iter.peek_front_nth(2), None);
+        assert_eq!(iter.peek_back_nth(1), Some(&4));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next_if_eq(&5), None);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.peek_front(), Some(&1));
+        assert_eq!(iter.next_back_if_eq(&5), Some(5));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        // Front and back slots meeting in the middle.
+        let mut short = peekablede(0..3);
+        assert_eq!(short.peek_back_nth(1), Some(&1));
+        assert_eq!(short.peek_front_nth(1), Some(&1));
+        assert_eq!(short.peek_front(), Some(&0));
+        assert_eq!(short.collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let mut pair = peekablede(0..2);
+        let _ = pair.peek_front_nth(1);
+        assert_eq!(pair.next_back(), Some(1));
+        assert_eq!(pair.next_back(), Some(0));
+        assert_eq!(pair.next(), None);
+    }
+
+    #[test]
+    fn test_peek_error_display() {
+        use peeknth::PeekError;
+
+        let cases = [
+            (
+                PeekError::OverCapacity {
+                    requested: 4,
+                    capacity: 2,
+                },
+                "peek index 4 is out of bounds for a buffer of capacity 2",
+            ),
+            (
+                PeekError::BufferOverflow { capacity: 8 },
+                "buffer overflow: capacity is 8",
+            ),
+            (
+                PeekError::ReservedLast,
+                "an item reserved by peek_last cannot be carried over",
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_peekn_peek_last() {
+        let mut iter = peekn(0..5);
+        assert_eq!(iter.peek_last(), Some(&4));
+        assert_eq!(iter.peek_last(), Some(&4));
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.peek_nth(4), Some(&4));
+        assert_eq!(iter.peek_nth(5), None);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+        let mut iter = peekn(0..3);
+        assert_eq!(iter.peek_last(), Some(&2));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.peek_last(), Some(&1));
+        assert_eq!(iter.by_ref().last(), Some(1));
+
+        let mut buffered = peekn(0..2);
+        let _ = buffered.peek_nth(1);
+        assert_eq!(buffered.peek_last(), Some(&1));
+        assert_eq!(buffered.collect::<Vec<_>>(), vec![0, 1]);
+
+        let mut empty = peekn(0..0);
+        assert_eq!(empty.peek_last(), None);
+    }
+
+    #[test]
+    fn test_peek_range_clone() {
+        let mut iter = peekn(0..10);
+        let range = iter.peek_range(1..4);
+        let copy = range.clone();
+        assert_eq!(range.copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(copy.len(), 3);
+        assert_eq!(copy.rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let mut dn = peekdn(0..10);
+        let _ = dn.peek_back_nth(1);
+        let front = dn.peek_front_range(0..3);
+        assert!(front.clone().eq(front));
+        let back = dn.peek_back_range(0..2);
+        assert_eq!(back.clone().count() + back.count(), 4);
+
+        let mut sized = sizedpeekdn::<_, 4, 4>(0..10);
+        let front = sized.peek_front_range(0..2);
+        assert_eq!(front.clone().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(front.count(), 2);
+        let back = sized.peek_back_range(0..2);
+        assert!(back.clone().eq(back));
+    }
+
+    #[test]
+    fn test_peekdn_drain_peeked_iter() {
+        let mut iter = peekdn((0..10).map(|x| x.to_string()));
+        let front: Vec<String> = iter.peek_front_range(0..3).cloned().collect();
+        let back: Vec<String> = iter.peek_back_range(0..2).cloned().collect();
+
+        assert_eq!(iter.drain_front_peeked_iter(3).collect::<Vec<_>>(), front);
+        assert_eq!(iter.drain_back_peeked_iter(10).collect::<Vec<_>>(), back);
+        assert_eq!(back, vec!["9", "8"]);
+        assert_eq!(iter.drain_front_peeked_iter(1).count(), 0);
+        assert_eq!(iter.next().as_deref(), Some("3"));
+        assert_eq!(iter.next_back().as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn test_peekdn_split_while() {
+        let mut iter = peekdn(0..10);
+        assert_eq!(iter.split_front_while(|&x| x < 3), vec![0, 1, 2]);
+        assert_eq!(iter.peek_front(), Some(&3));
+        assert_eq!(iter.split_back_while(|&x| x > 6), vec![9, 8, 7]);
+        assert_eq!(iter.peek_back(), Some(&6));
+        assert_eq!(iter.split_front_while(|_| true), vec![3, 4, 5, 6]);
+        assert_eq!(iter.split_back_while(|_| true), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_peekdn_split_while_prefilled() {
+        let mut iter = peekdn(0..10);
+        iter.peek_front_nth(5);
+        assert_eq!(iter.split_front_while(|&x| x < 3), vec![0, 1, 2]);
+        assert_eq!(iter.by_ref().take(4).collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+
+        let mut iter = peekdn(0..10);
+        iter.peek_back_nth(5);
+        assert_eq!(iter.split_back_while(|&x| x > 6), vec![9, 8, 7]);
+        assert_eq!(
+            iter.by_ref().rev().take(4).collect::<Vec<_>>(),
+            vec![6, 5, 4, 3]
+        );
+    }
+
+    #[test]
+    fn test_next_if_prefilled() {
+        let mut iter = peekdn(0..6);
+        iter.peek_front_nth(2);
+        assert_eq!(iter.next_if(|_| false), None);
+        assert_eq!(iter.next_back_if(|_| false), None);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+        let mut iter = peekdn(0..4);
+        iter.peek_back_nth(3);
+        assert_eq!(iter.next_if(|_| false), None);
+        assert_eq!(iter.next_back_if(|_| false), None);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let mut sized = sizedpeekdn::<_, 4, 4>(0..6);
+        sized.peek_front_nth(2);
+        assert_eq!(sized.next_if(|_| false), None);
+        assert_eq!(sized.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+        let mut sized = sizedpeekdn::<_, 4, 4>(0..6);
+        sized.peek_back_nth(2);
+        assert_eq!(sized.next_back_if(|_| false), None);
+        assert_eq!(sized.rev().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_sized_smallvec_buffers() {
+        use smallvec::SmallVec;
+
+        let mut sized = SizedPeekN::<_, 2, SmallVec<[i32; 2]>>::with_buffer(0..10, SmallVec::new());
+        assert_eq!(sized.capacity(), usize::MAX);
+        assert_eq!(sized.peek_nth(5), Some(&5));
+        assert_eq!(sized.peek_range(1..4).collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(sized.next(), Some(0));
+        assert_eq!(sized.collect::<Vec<_>>(), (1..10).collect::<Vec<_>>());
+
+        let mut dn = SizedPeekDN::<_, 1, 1, SmallVec<[i32; 1]>, SmallVec<[i32; 1]>>::with_buffers(
+            0..10,
+            SmallVec::new(),
+            SmallVec::new(),
+        );
+        assert_eq!(dn.peek_front_nth(3), Some(&3));
+        assert_eq!(dn.peek_back_nth(2), Some(&7));
+        assert_eq!(dn.next(), Some(0));
+        assert_eq!(dn.next_back(), Some(9));
+        assert_eq!(dn.collect::<Vec<_>>(), (1..9).collect::<Vec<_>>());
+
+        // The default inline buffer can be named to mix it with another backend.
+        use peeknth::buffer::Buffer;
+        let mut mixed = SizedPeekDN::<_, 2, 1, Buffer<i32, 2>, SmallVec<[i32; 1]>>::with_buffers(
+            0..5,
+            Buffer::new(),
+            SmallVec::new(),
+        );
+        assert_eq!(mixed.peek_back_nth(2), Some(&2));
+        assert_eq!(mixed.peek_front_nth(1), Some(&1));
+        assert_eq!(mixed.collect::<Vec<_>>(), (0..5).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_checksummed_ignores_peeks() {
+        let mut iter = peekn(1..=5).checksummed(0, |sum, x| *sum += x);
+        assert_eq!(iter.peek_nth(4), Some(&5));
+        assert_eq!(*iter.checksum(), 0);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(*iter.checksum(), 1);
+        assert_eq!(iter.by_ref().take(2).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(*iter.checksum(), 6);
+
+        let (mut rest, sum) = iter.into_parts();
+        assert_eq!(sum, 6);
+        assert_eq!(rest.next(), Some(4));
+    }
+
+    #[test]
+    fn test_peek_until_without_match() {
+        let mut iter = peekn([1, 2, 3].into_iter());
+        assert_eq!(
+            iter.peek_until(|&x| x == 0).collect::<Vec<_>>(),
+            vec![&1, &2, &3]
+        );
+        assert_eq!(iter.peek_until_within(2, |&x| x == 0).count(), 2);
+        assert_eq!(iter.next(), Some(1));
+
+        assert_eq!(iter.next_until(|&x| x == 0).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(iter.next(), None);
+
+        let mut empty = peekn(core::iter::empty::<i32>());
+        assert_eq!(empty.peek_until(|_| true).count(), 0);
+    }
+
+    #[test]
+    fn test_peek_with_run() {
+        let mut iter = peekn([5, 5, 5, 6].into_iter());
+        assert_eq!(iter.peek_with_run(), Some((&5, 3)));
+        assert_eq!(iter.peek_with_run(), Some((&5, 3)));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.peek_with_run(), Some((&5, 2)));
+        iter.nth(1);
+        assert_eq!(iter.peek_with_run(), Some((&6, 1)));
+        iter.next();
+        assert_eq!(iter.peek_with_run(), None);
+    }
+
+    #[test]
+    fn test_reserve_and_shrink() {
+        let mut iter = peekn(0..100);
+        iter.reserve(32);
+        assert!(iter.peeked_capacity() >= 32);
+        assert_eq!(iter.peek_nth(3), Some(&3));
+        iter.shrink_to_fit();
+        assert!(iter.peeked_capacity() >= 4);
+        assert_eq!(
+            iter.by_ref().take(5).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        iter.shrink_to_fit();
+        assert_eq!(iter.peeked_capacity(), 0);
+
+        let mut dn = peekdn(0..100);
+        dn.reserve_front(8);
+        dn.reserve_back(16);
+        assert!(dn.front_peeked_capacity() >= 8);
+        assert!(dn.back_peeked_capacity() >= 16);
+        assert_eq!(dn.peek_front_nth(2), Some(&2));
+        assert_eq!(dn.peek_back_nth(1), Some(&98));
+        dn.shrink_to_fit();
+        assert_eq!(dn.next(), Some(0));
+        assert_eq!(dn.next_back(), Some(99));
+        dn.clear_peeked();
+        dn.shrink_front();
+        dn.shrink_back();
+        assert_eq!(dn.front_peeked_capacity(), 0);
+        assert_eq!(dn.back_peeked_capacity(), 0);
+    }
+
+    #[test]
+    fn test_peeking_take_while() {
+        let mut iter = peekn(1..10);
+        let small: Vec<_> = iter.peeking_take_while(|&x| x < 4).collect();
+        assert_eq!(small, vec![1, 2, 3]);
+        assert_eq!(iter.peek(), Some(&4));
+        assert_eq!(iter.peeking_take_while(|&x| x > 100).count(), 0);
+        assert_eq!(iter.collect::<Vec<_>>(), (4..10).collect::<Vec<_>>());
+
+        let mut sized = sizedpeekn::<_, 1>("  word".chars());
+        assert_eq!(sized.peeking_take_while(|c| c.is_whitespace()).count(), 2);
+        assert_eq!(sized.collect::<String>(), "word");
+    }
+
+    #[test]
+    fn test_extend_after_peeked_items() {
+        let mut iter = peekn(0..5);
+        assert_eq!(iter.peek_nth(1), Some(&1));
+        iter.extend([100, 101, 102]);
+        assert_eq!(iter.peeked_len(), 5);
+        assert_eq!(iter.next(), Some(0));
+        iter.extend(core::iter::once(103));
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![1, 100, 101, 102, 103, 2, 3, 4]
+        );
+
+        let mut fresh = peekn(0..2);
+        fresh.extend([7, 8, 9]);
+        assert_eq!(fresh.collect::<Vec<_>>(), vec![7, 8, 9, 0, 1]);
+    }
+
+    #[test]
+    fn test_itertools_peeking_next() {
+        use itertools::{Itertools, PeekingNext};
+
+        let mut iter = peekn(1..10);
+        let small: Vec<_> = Itertools::peeking_take_while(&mut iter, |&x| x < 4).collect();
+        assert_eq!(small, vec![1, 2, 3]);
+        assert_eq!(iter.peek(), Some(&4));
+
+        let mut sized = sizedpeekn::<_, 2>("ab1".chars());
+        let letters: String =
+            Itertools::peeking_take_while(&mut sized, |c| c.is_alphabetic()).collect();
+        assert_eq!(letters, "ab");
+        assert_eq!(sized.next(), Some('1'));
+
+        let mut dn = peekdn(0..5);
+        assert_eq!(dn.peeking_next(|&x| x == 1), None);
+        assert_eq!(dn.peeking_next(|&x| x == 0), Some(0));
+
+        let mut dn = peekdn(0..10);
+        dn.peek_front_nth(3);
+        let small: Vec<_> = Itertools::peeking_take_while(&mut dn, |&x| x < 2).collect();
+        assert_eq!(small, vec![0, 1]);
+        assert_eq!(dn.collect::<Vec<_>>(), (2..10).collect::<Vec<_>>());
+
+        let mut de = peekablede(0..5);
+        assert_eq!(de.peeking_next(|&x| x > 0), None);
+        assert_eq!(de.next(), Some(0));
+    }
+
+    #[test]
+    fn test_peek_position_keeps_buffer() {
+        let mut pulled = 0;
+        let mut iter = peekn([4, 6, 8, 9, 10].into_iter().inspect(|_| pulled += 1));
+        assert_eq!(iter.peek_position(|&x| x % 2 == 1), Some(3));
+        assert_eq!(iter.peeked_len(), 4);
+        assert_eq!(iter.peek_nth(3), Some(&9));
+        assert_eq!(iter.peeked_len(), 4);
+        assert_eq!(iter.peek_position_within(2, |&x| x == 10), None);
+        assert_eq!(iter.peek_position_within(5, |&x| x == 10), Some(4));
+        drop(iter);
+        assert_eq!(pulled, 5);
+
+        let mut dn = peekdn(0..10);
+        assert_eq!(dn.peek_front_position(|&x| x == 4), Some(4));
+        assert_eq!(dn.peek_back_position(|&x| x == 8), Some(1));
+    }
+
+    #[test]
+    fn test_peek_group_runs() {
+        let mut iter = peekn([1, 1, 2, 3, 3, 3].into_iter());
+        let runs: Vec<_> = iter.peek_group_runs(|&x| x % 2 == 1).collect();
+        assert_eq!(runs, vec![(true, 2), (false, 1), (true, 3)]);
+        assert_eq!(iter.peeked_len(), 6);
+        assert_eq!(
+            iter.peek_group_runs(|&x| x).collect::<Vec<_>>(),
+            vec![(1, 2), (2, 1), (3, 3)]
+        );
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 1, 2, 3, 3, 3]);
+
+        let mut empty = peekn(0..0);
+        assert_eq!(empty.peek_group_runs(|&x| x).count(), 0);
+    }
+
+    #[test]
+    fn test_skip_peeked_spills_into_iterator() {
+        let mut iter = peekn(0..10);
+        assert_eq!(iter.peek_nth(2), Some(&2));
+        assert_eq!(iter.skip_peeked(5), 5);
+        assert_eq!(iter.peeked_len(), 0);
+        assert_eq!(iter.next(), Some(5));
+
+        assert_eq!(iter.skip_peeked(0), 0);
+        assert_eq!(iter.peek(), Some(&6));
+        assert_eq!(iter.skip_peeked(1), 1);
+        assert_eq!(iter.skip_peeked(100), 3);
+        assert_eq!(iter.next(), None);
+    }
+
+    mod zst {
+        use super::*;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Marker;
+
+        #[test]
+        fn test_zst_peekn() {
+            let mut iter = peekn(core::iter::repeat_n((), 5));
+            assert_eq!(iter.peek_nth(3), Some(&()));
+            assert_eq!(iter.peeked_len(), 4);
+            assert_eq!(iter.peek_range(1..4).count(), 3);
+            assert_eq!(iter.next_if(|_| true), Some(()));
+            assert_eq!(iter.peek_nth(4), None);
+            assert_eq!(iter.count(), 4);
+
+            let mut markers = peekn(core::iter::repeat(Marker));
+            assert_eq!(markers.peek_nth(99), Some(&Marker));
+            assert_eq!(markers.peeked_len(), 100);
+            assert_eq!(markers.skip_peeked(100), 100);
+            assert_eq!(markers.peeked_len(), 0);
+        }
+
+        #[test]
+        fn test_zst_sized_peekn() {
+            let mut iter = sizedpeekn::<_, 3>(core::iter::repeat_n(Marker, 7));
+            assert_eq!(iter.peek_nth(2), Some(&Marker));
+            assert_eq!(iter.peek_range(..3).collect::<Vec<_>>(), vec![&Marker; 3]);
+
+            // Consume and refill so the ring buffer wraps around.
+            for _ in 0..5 {
+                assert_eq!(iter.next(), Some(Marker));
+                assert_eq!(iter.peek_nth(1), Some(&Marker));
+            }
+            assert_eq!(iter.peek_range(0..2).count(), 2);
+            assert_eq!(iter.peek_range_mut(0..2).count(), 2);
+            assert_eq!(iter.next_if_eq(&Marker), Some(Marker));
+            assert_eq!(iter.next_if(|_| false), None);
+            assert_eq!(iter.next(), Some(Marker));
+            assert_eq!(iter.peek(), None);
+            assert_eq!(iter.peek_range(..).count(), 0);
+        }
+
+        #[test]
+        fn test_zst_sized_peekdn() {
+            let mut iter = sizedpeekdn::<_, 2, 2>(core::iter::repeat_n((), 6));
+            assert_eq!(iter.peek_front_nth(1), Some(&()));
+            assert_eq!(iter.peek_back_nth(1), Some(&()));
+            assert_eq!(iter.next(), Some(()));
+            assert_eq!(iter.next_back(), Some(()));
+            assert_eq!(iter.count(), 4);
+        }
+    }
+
+    #[test]
+    fn test_next_if_seq() {
+        let digit = |c: &char| c.is_ascii_digit();
+        let dot = |c: &char| *c == '.';
+        let pattern: [&dyn Fn(&char) -> bool; 3] = [&digit, &dot, &digit];
+
+        let mut iter = peekn("3.14".chars());
+        assert_eq!(iter.next_if_seq(&pattern), Some(vec!['3', '.', '1']));
+        assert_eq!(iter.next(), Some('4'));
+
+        let mut mismatch = peekn("3,14".chars());
+        assert_eq!(mismatch.next_if_seq(&pattern), None);
+        assert_eq!(mismatch.next(), Some('3'));
+
+        let mut short = peekn("3.".chars());
+        assert_eq!(short.next_if_seq(&pattern), None);
+        assert_eq!(short.collect::<String>(), "3.");
+
+        let mut any = peekn("x".chars());
+        assert_eq!(any.next_if_seq(&[]), Some(vec![]));
+        assert_eq!(any.next(), Some('x'));
+    }
+
+    #[test]
+    fn test_try_from_peekn_for_sized() {
+        let mut iter = peekn(0..10);
+        let _ = iter.peek_nth(1);
+        let mut sized = SizedPeekN::<_, 2>::try_from(iter).unwrap();
+        assert_eq!(sized.peek_nth(1), Some(&1));
+        assert_eq!(sized.collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+
+        let mut deep = peekn(0..10);
+        let _ = deep.peek_nth(4);
+        let err = SizedPeekN::<_, 2>::try_from(deep).unwrap_err();
+        assert_eq!(err.error(), PeekError::BufferOverflow { capacity: 2 });
+        assert_eq!(err.get_ref().peeked_len(), 5);
+        let mut back = err.into_inner();
+        assert_eq!(back.next(), Some(0));
+
+        let mut reserved = peekn(0..3);
+        assert_eq!(reserved.peek_last(), Some(&2));
+        let err = SizedPeekN::<_, 4>::try_from(reserved).unwrap_err();
+        assert_eq!(err.error(), PeekError::ReservedLast);
+        let reserved = err.into_inner();
+        assert_eq!(reserved.peeked_len(), 0);
+        assert_eq!(reserved.collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_peek_balanced() {
+        let open = |c: &char| *c == '(';
+        let close = |c: &char| *c == ')';
+
+        let mut iter = peekn(['(', '(', ')', ')'].into_iter());
+        assert_eq!(iter.peek_balanced(open, close), Some(3));
+        assert_eq!(iter.next(), Some('('));
+        assert_eq!(iter.peek_balanced(open, close), Some(1));
+
+        let mut unbalanced = peekn("((x)".chars());
+        assert_eq!(unbalanced.peek_balanced(open, close), None);
+        assert_eq!(unbalanced.peeked_len(), 4);
+
+        let mut not_opener = peekn("x()".chars());
+        assert_eq!(not_opener.peek_balanced(open, close), None);
+
+        let quote = |c: &char| *c == '"';
+        let mut quoted = peekn(r#""ab"c"#.chars());
+        assert_eq!(quoted.peek_balanced(quote, quote), Some(3));
+    }
+
+    #[test]
+    fn test_peekdn_reversed_keeps_buffers() {
+        let mut iter = peekdn(0..=5);
+        assert_eq!(iter.peek_front_nth(1), Some(&1));
+        assert_eq!(iter.peek_back_nth(1), Some(&4));
+
+        let mut rev = iter.reversed();
+        assert_eq!(rev.front_peeked_len(), 2);
+        assert_eq!(rev.back_peeked_len(), 2);
+        assert_eq!(rev.peek_front_nth(0), Some(&5));
+        assert_eq!(rev.peek_front_nth(2), Some(&3));
+        assert_eq!(rev.peek_back_nth(0), Some(&0));
+        assert_eq!(rev.peek_back_nth(1), Some(&1));
+        assert_eq!(rev.next(), Some(5));
+        assert_eq!(rev.next_back(), Some(0));
+
+        let mut again = rev.reversed();
+        assert_eq!(again.peek_front(), Some(&1));
+        assert_eq!(again.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_scan_with_peek() {
+        let diffs: Vec<_> = peekn([1, 3, 6].into_iter())
+            .scan_with_peek((), |_, x, next| next.map(|n| n - x))
+            .collect();
+        assert_eq!(diffs, vec![2, 3]);
+
+        // Running total that marks the last item.
+        let totals: Vec<_> = peekn(1..=4)
+            .scan_with_peek(0, |sum, x, next| {
+                *sum += x;
+                Some((*sum, next.is_none()))
+            })
+            .collect();
+        assert_eq!(totals, vec![(1, false), (3, false), (6, false), (10, true)]);
+
+        let empty: Vec<i32> = peekn(0..0).scan_with_peek((), |_, x, _| Some(x)).collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sized_try_from_peekablede_second_slots() {
+        let mut de = peekablede(0..10);
+        assert_eq!(de.peek_front_nth(1), Some(&1));
+        assert_eq!(de.peek_back_nth(1), Some(&8));
+        let mut sized = SizedPeekDN::<_, 2, 2>::try_from(de).unwrap();
+        assert_eq!(sized.front_peeked_len(), 2);
+        assert_eq!(sized.back_peeked_len(), 2);
+        assert_eq!(sized.peek_front_nth(1), Some(&1));
+        assert_eq!(sized.peek_back_nth(1), Some(&8));
+        assert_eq!(sized.collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+
+        let mut de = peekablede(0..10);
+        let _ = de.peek_front_nth(1);
+        let err = SizedPeekDN::<_, 1, 1>::try_from(de).unwrap_err();
+        assert_eq!(err.error(), PeekError::BufferOverflow { capacity: 1 });
+        assert_eq!(
+            err.into_inner().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+
+        let mut de = peekablede(0..10);
+        let _ = de.peek_back_nth(1);
+        assert!(SizedPeekDN::<_, 2, 1>::try_from(de).is_err());
+        let mut de = peekablede(0..10);
+        let _ = de.peek_front();
+        assert!(SizedPeekDN::<_, 1, 0>::try_from(de).is_ok());
+    }
+
+    #[test]
+    fn test_peekn_try_from_peekablede() {
+        let mut de = peekablede(0..10);
+        assert_eq!(de.peek_front_nth(1), Some(&1));
+        assert_eq!(de.peek_back(), Some(&9));
+        let mut iter = PeekN::try_from(de).unwrap();
+        assert_eq!(iter.peeked_len(), 2);
+        assert_eq!(iter.peek_last(), Some(&9));
+        assert_eq!(iter.collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+
+        let mut de = peekablede(0..10);
+        let _ = de.peek_back_nth(1);
+        let err = PeekN::try_from(de).unwrap_err();
+        assert_eq!(err.error(), PeekError::BufferOverflow { capacity: 1 });
+        assert_eq!(
+            err.into_inner().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+
+        // Once the inner iterator is drained, both back slots fit in the peek buffer.
+        let mut de = peekablede(0..2);
+        let _ = de.peek_back_nth(1);
+        assert_eq!(de.peek_front(), Some(&0));
+        let iter = PeekN::try_from(de).unwrap();
+        assert_eq!(iter.peeked_len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1]);
+    }
 }